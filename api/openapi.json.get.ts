@@ -0,0 +1,560 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["meta"],
+    summary: "OpenAPI spec",
+    description: "Returns the OpenAPI spec of every route, with the registered tags and shared components.",
+    responses: {
+      "200": {
+        description: "The OpenAPI spec",
+        content: {
+          "application/json": {
+            schema: { type: "object", description: "An OpenAPI 3 document." },
+          },
+        },
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
+// shared components referenced by every route, `defineRouteMeta` is extracted at build time,
+// so it can't reference the error codes.
+const OPENAPI_COMPONENTS = {
+  parameters: {
+    username: {
+      in: "path",
+      name: "username",
+      required: true,
+      description: "The owner of the repository.",
+      schema: { type: "string" },
+    },
+    repositoryName: {
+      in: "path",
+      name: "repositoryName",
+      required: true,
+      description: "The name of the repository.",
+      schema: { type: "string" },
+    },
+    lang: {
+      in: "query",
+      name: "lang",
+      description: "Comma separated locales to localize descriptions in, e.g. `da,en`. Overrides `Accept-Language`.",
+      schema: { type: "string" },
+    },
+    limit: {
+      in: "query",
+      name: "limit",
+      description: "The number of items per page, at most `pagination.maxPageSize`.",
+      schema: { type: "integer", minimum: 1 },
+    },
+    cursor: {
+      in: "query",
+      name: "cursor",
+      description: "The `nextCursor` of the previous page.",
+      schema: { type: "string" },
+    },
+  },
+  schemas: {
+    ResolvedProject: {
+      type: "object",
+      description: "A project resolved from its `mosaic.toml`.",
+      required: ["name", "priority", "ignore"],
+      properties: {
+        name: { type: "string" },
+        owner: { type: "string", description: "The user or organization owning the repository." },
+        handle: { type: "string", description: "The handle of the project, used in urls on `luxass.dev`." },
+        description: { type: "string", description: "The description, localized to the requested locales." },
+        locales: {
+          type: "array",
+          description: "The locales the localized fields of the project are available in.",
+          items: { type: "string" },
+        },
+        priority: { type: "number" },
+        ignore: { type: "boolean" },
+        version: { type: "string", description: "The version, when `project.version` is enabled." },
+        stars: { type: "integer" },
+        downloads: { type: "integer", description: "The total downloads of the release assets." },
+        license: { type: "string", description: "The SPDX id of the license." },
+        readme: { type: "string", format: "uri", description: "The url of the rendered README." },
+        website: {
+          type: "object",
+          properties: {
+            url: { type: "string", format: "uri", nullable: true },
+            title: { type: "string" },
+            description: { type: "string" },
+            keywords: { type: "array", items: { type: "string" } },
+            vercel: { type: "string" },
+            deployment: {
+              type: "object",
+              required: ["broken"],
+              properties: {
+                state: { type: "string" },
+                url: { type: "string", format: "uri" },
+                createdAt: { type: "string", format: "date-time" },
+                broken: { type: "boolean" },
+              },
+            },
+          },
+        },
+        npm: {
+          type: "object",
+          properties: {
+            name: { type: "string" },
+            url: { type: "string", format: "uri" },
+            downloads: { type: "integer", description: "The downloads of the last month." },
+          },
+        },
+        deprecated: {
+          type: "object",
+          required: ["message"],
+          properties: {
+            message: { type: "string" },
+            replacement: { type: "string" },
+          },
+        },
+        funding: {
+          type: "array",
+          items: { $ref: "#/components/schemas/FundingLink" },
+        },
+      },
+    },
+    Project: {
+      type: "object",
+      description: "A project showcased on `luxass.dev`, as listed by the worker.",
+      required: ["nameWithOwner"],
+      properties: {
+        nameWithOwner: { type: "string" },
+        license: { type: "string", description: "The SPDX id of the license." },
+      },
+      additionalProperties: true,
+    },
+    FundingLink: {
+      type: "object",
+      required: ["platform", "url"],
+      properties: {
+        platform: { type: "string", description: "The platform as named in `FUNDING.yml`, e.g. `github` or `ko_fi`." },
+        url: { type: "string", format: "uri" },
+      },
+    },
+    Page: {
+      type: "object",
+      description: "A page of a paginated list, the routes using it describe the type of `items`.",
+      required: ["items", "nextCursor"],
+      properties: {
+        items: { type: "array", items: {} },
+        nextCursor: {
+          type: "string",
+          nullable: true,
+          description: "Pass as `?cursor=` to get the next page, `null` on the last page.",
+        },
+      },
+    },
+    MosaicConfig: {
+      type: "object",
+      description: "A resolved `mosaic.toml`.",
+      externalDocs: {
+        description: "mosaic.toml reference",
+        url: "https://mosaic.luxass.dev/schemas/mosaic",
+      },
+    },
+    ConfigWarning: {
+      type: "object",
+      description: "A deprecated key used by a config, which is migrated until `removeAfter`.",
+      required: ["key", "message", "removeAfter"],
+      properties: {
+        key: { type: "string", description: "The dotted path of the deprecated key." },
+        replacement: { type: "string", description: "The key to use instead, missing when the key was removed without a replacement." },
+        message: { type: "string" },
+        removeAfter: { type: "string", format: "date" },
+      },
+    },
+    Diagnostic: {
+      type: "object",
+      required: ["level", "message"],
+      properties: {
+        level: { type: "string", enum: ["warning", "error"] },
+        message: { type: "string" },
+      },
+    },
+    ChecklistItem: {
+      type: "object",
+      required: ["id", "label", "done", "fix"],
+      properties: {
+        id: { type: "string" },
+        label: { type: "string" },
+        done: { type: "boolean" },
+        fix: { type: "string", description: "Where the item can be fixed, or found when it is done." },
+      },
+    },
+    Language: {
+      type: "object",
+      required: ["name", "color"],
+      properties: {
+        name: { type: "string" },
+        color: { type: "string", description: "The color of the language on GitHub, e.g. `#3178c6`." },
+      },
+    },
+    DocPage: {
+      type: "object",
+      required: ["slug", "path", "url"],
+      properties: {
+        slug: { type: "string" },
+        path: { type: "string", description: "The path of the page in the repository." },
+        title: { type: "string" },
+        url: { type: "string", format: "uri" },
+      },
+    },
+    ProjectCard: {
+      type: "object",
+      required: ["name", "nameWithOwner", "url", "stars", "languages", "ogImage"],
+      properties: {
+        name: { type: "string" },
+        nameWithOwner: { type: "string" },
+        description: { type: "string", description: "The description, localized to the requested locales." },
+        url: { type: "string", format: "uri" },
+        website: { type: "string", format: "uri" },
+        stars: { type: "integer" },
+        languages: { type: "array", items: { $ref: "#/components/schemas/Language" } },
+        ogImage: { type: "string", format: "uri" },
+      },
+    },
+    Readme: {
+      type: "object",
+      required: ["lastModified", "content", "path"],
+      properties: {
+        lastModified: { type: "string", format: "date-time" },
+        content: { type: "string", description: "The markdown of the document." },
+        path: { type: "string", description: "The path of the document in the repository." },
+      },
+    },
+    Owner: {
+      type: "object",
+      required: ["login", "type"],
+      properties: {
+        login: { type: "string" },
+        type: { type: "string", enum: ["user", "organization"] },
+        siteUrl: { type: "string", format: "uri", description: "The site showcasing the projects of the owner." },
+      },
+    },
+    Repository: {
+      type: "object",
+      required: ["owner", "ownerType", "name", "nameWithOwner", "description", "url", "isFork", "isArchived", "pushedAt", "stars", "forks", "openIssues", "license"],
+      properties: {
+        owner: { type: "string" },
+        ownerType: { type: "string", enum: ["user", "organization"] },
+        name: { type: "string" },
+        nameWithOwner: { type: "string" },
+        description: { type: "string", nullable: true },
+        url: { type: "string", format: "uri" },
+        isFork: { type: "boolean" },
+        isArchived: { type: "boolean" },
+        pushedAt: { type: "string", format: "date-time", nullable: true },
+        stars: { type: "integer" },
+        forks: { type: "integer" },
+        openIssues: { type: "integer" },
+        license: { type: "string", nullable: true, description: "The SPDX id of the license." },
+      },
+    },
+    Stack: {
+      type: "object",
+      required: ["id", "name"],
+      properties: {
+        id: { type: "string", description: "The id of the stack, e.g. `nitro`." },
+        name: { type: "string" },
+      },
+    },
+    Dependency: {
+      type: "object",
+      required: ["name", "ecosystem"],
+      properties: {
+        name: { type: "string" },
+        ecosystem: { type: "string", enum: ["npm", "cargo", "pypi"] },
+        version: { type: "string", description: "The version requirement, e.g. `^1.0.0`." },
+      },
+    },
+    ProjectStack: {
+      type: "object",
+      required: ["owner", "repository", "syncedAt", "stacks", "dependencies"],
+      properties: {
+        owner: { type: "string" },
+        repository: { type: "string" },
+        name: { type: "string" },
+        syncedAt: { type: "string", format: "date-time" },
+        stacks: { type: "array", items: { $ref: "#/components/schemas/Stack" } },
+        dependencies: { type: "array", items: { $ref: "#/components/schemas/Dependency" } },
+      },
+    },
+    StatsSnapshot: {
+      type: "object",
+      required: ["date", "stars", "forks", "openIssues"],
+      properties: {
+        date: { type: "string", format: "date" },
+        stars: { type: "integer" },
+        forks: { type: "integer" },
+        openIssues: { type: "integer" },
+      },
+    },
+    StatsDelta: {
+      type: "object",
+      required: ["stars", "forks", "openIssues"],
+      properties: {
+        stars: { type: "integer" },
+        forks: { type: "integer" },
+        openIssues: { type: "integer" },
+      },
+    },
+    GitHubEvent: {
+      type: "object",
+      required: ["id", "type", "repo"],
+      properties: {
+        id: { type: "string" },
+        type: { type: "string", nullable: true, description: "The type of the event, e.g. `PushEvent`." },
+        created_at: { type: "string", format: "date-time", nullable: true },
+        repo: { type: "string", description: "The `owner/name` of the repository." },
+      },
+    },
+    ReleaseAsset: {
+      type: "object",
+      required: ["name", "url", "size", "downloads"],
+      properties: {
+        name: { type: "string" },
+        url: { type: "string", format: "uri" },
+        size: { type: "integer" },
+        downloads: { type: "integer" },
+      },
+    },
+    StoredConfigWarnings: {
+      type: "object",
+      required: ["owner", "repository", "path", "updatedAt", "warnings"],
+      properties: {
+        owner: { type: "string" },
+        repository: { type: "string" },
+        path: { type: "string", description: "The url of the config." },
+        updatedAt: { type: "string", format: "date-time" },
+        warnings: { type: "array", items: { $ref: "#/components/schemas/ConfigWarning" } },
+      },
+    },
+    ShadowDiff: {
+      type: "object",
+      required: ["path", "recordedAt", "status", "differences"],
+      properties: {
+        path: { type: "string" },
+        requestId: { type: "string" },
+        recordedAt: { type: "string", format: "date-time" },
+        status: {
+          type: "object",
+          required: ["production"],
+          properties: {
+            production: { type: "integer" },
+            staging: { type: "integer" },
+          },
+        },
+        differences: { type: "array", description: "The JSON paths at which the responses differ.", items: { type: "string" } },
+        error: { type: "string" },
+      },
+    },
+    SpecChanges: {
+      type: "object",
+      required: ["added", "removed", "changed"],
+      properties: {
+        added: { type: "array", items: { type: "string" } },
+        removed: { type: "array", items: { type: "string" } },
+        changed: { type: "array", items: { type: "string" } },
+      },
+    },
+    SyncRuleMatch: {
+      type: "object",
+      required: ["nameWithOwner", "included"],
+      properties: {
+        nameWithOwner: { type: "string" },
+        included: { type: "boolean" },
+        rule: { type: "string", description: "The last rule matching the repository, missing when no rule matched." },
+      },
+    },
+    InboxItem: {
+      type: "object",
+      required: ["id", "source", "status", "title", "occurrences", "createdAt", "updatedAt"],
+      properties: {
+        id: { type: "string" },
+        source: { type: "string", enum: ["report", "link_check", "job"] },
+        status: { type: "string", enum: ["new", "acked", "resolved"] },
+        title: { type: "string" },
+        owner: { type: "string" },
+        repository: { type: "string" },
+        dedupeKey: { type: "string", description: "Items with the same key are merged while unresolved." },
+        occurrences: { type: "integer", description: "The number of times the item was reported." },
+        data: { description: "Details depending on the source." },
+        createdAt: { type: "string", format: "date-time" },
+        updatedAt: { type: "string", format: "date-time" },
+      },
+    },
+    SyncFailure: {
+      type: "object",
+      required: ["job", "owner", "repository", "error", "count", "firstFailedAt", "lastFailedAt", "nextRetryAt", "deadLettered"],
+      properties: {
+        job: { type: "string" },
+        owner: { type: "string" },
+        repository: { type: "string" },
+        error: { type: "string" },
+        count: { type: "integer", description: "The number of consecutive failed attempts." },
+        firstFailedAt: { type: "string", format: "date-time" },
+        lastFailedAt: { type: "string", format: "date-time" },
+        nextRetryAt: { type: "string", format: "date-time", nullable: true, description: "`null` once the project is dead-lettered." },
+        deadLettered: { type: "boolean" },
+      },
+    },
+    JobRun: {
+      type: "object",
+      required: ["name", "status", "trigger", "startedAt"],
+      properties: {
+        name: { type: "string" },
+        status: { type: "string", enum: ["running", "succeeded", "failed", "interrupted"] },
+        trigger: { type: "string", enum: ["scheduled", "manual"] },
+        startedAt: { type: "string", format: "date-time" },
+        finishedAt: { type: "string", format: "date-time" },
+        duration: { type: "integer", description: "The duration of the run in milliseconds." },
+        result: { description: "What the job returned." },
+        error: { type: "string" },
+      },
+    },
+    SchedulerState: {
+      type: "object",
+      required: ["paused"],
+      properties: {
+        paused: { type: "boolean", description: "Whether scheduled runs are skipped." },
+      },
+    },
+    ApiErrorResponse: {
+      type: "object",
+      description: "An RFC 7807 problem details object.",
+      required: ["type", "title", "status", "detail", "instance", "code", "trace_id", "timestamp"],
+      properties: {
+        type: { type: "string", format: "uri", description: "A URI identifying the problem type." },
+        title: { type: "string", description: "A short summary of the problem type." },
+        status: { type: "integer", description: "The HTTP status code." },
+        detail: { type: "string", description: "A human readable error message." },
+        instance: { type: "string", description: "The path of the request." },
+        code: {
+          type: "string",
+          description: "A stable, machine-readable error code.",
+          enum: API_ERROR_CODES,
+        },
+        trace_id: { type: "string", description: "The id of the request, for correlating logs." },
+        timestamp: { type: "string", format: "date-time" },
+        errors: {
+          type: "array",
+          description: "Field-level details for validation errors.",
+          items: {
+            type: "object",
+            required: ["field", "message"],
+            properties: {
+              field: { type: "string" },
+              message: { type: "string" },
+            },
+          },
+        },
+      },
+    },
+  },
+  responses: {
+    BadRequest: {
+      description: "The request is invalid",
+      content: {
+        "application/problem+json": {
+          schema: { $ref: "#/components/schemas/ApiErrorResponse" },
+        },
+      },
+    },
+    Unauthorized: {
+      description: "The admin token is missing or invalid",
+      content: {
+        "application/problem+json": {
+          schema: { $ref: "#/components/schemas/ApiErrorResponse" },
+        },
+      },
+    },
+    NotFound: {
+      description: "The resource could not be found",
+      content: {
+        "application/problem+json": {
+          schema: { $ref: "#/components/schemas/ApiErrorResponse" },
+        },
+      },
+    },
+    InternalServerError: {
+      description: "An unexpected error occurred",
+      content: {
+        "application/problem+json": {
+          schema: { $ref: "#/components/schemas/ApiErrorResponse" },
+        },
+      },
+    },
+    TooManyRequests: {
+      description: "The client exceeded the rate limit",
+      headers: {
+        "Retry-After": {
+          description: "Seconds until the next request is allowed.",
+          schema: { type: "integer" },
+        },
+      },
+      content: {
+        "application/problem+json": {
+          schema: { $ref: "#/components/schemas/ApiErrorResponse" },
+        },
+      },
+    },
+    NotImplemented: {
+      description: "The requested feature is not supported",
+      content: {
+        "application/problem+json": {
+          schema: { $ref: "#/components/schemas/ApiErrorResponse" },
+        },
+      },
+    },
+    BadGateway: {
+      description: "An upstream service responded with an error",
+      content: {
+        "application/problem+json": {
+          schema: { $ref: "#/components/schemas/ApiErrorResponse" },
+        },
+      },
+    },
+  },
+};
+
+export default defineEventHandler(async () => {
+  // the spec generated by nitro, which has no support for top-level tags or shared components
+  const spec = await $fetch<{
+    paths: Record<string, Record<string, { tags?: string[] }>>;
+    components?: Record<string, Record<string, unknown>>;
+  }>("/_openapi.json");
+
+  const unregistered = findUnregisteredTags(spec.paths || {});
+
+  if (unregistered.length) {
+    const message = `operations with unregistered openapi tags, add them to OPENAPI_TAGS:\n${unregistered.join("\n")}`;
+
+    if (import.meta.dev) {
+      throw createApiError({
+        status: 500,
+        code: "internal_error",
+        message,
+      });
+    }
+
+    console.warn(message);
+  }
+
+  return {
+    ...spec,
+    tags: OPENAPI_TAGS,
+    components: {
+      ...spec.components,
+      parameters: { ...spec.components?.parameters, ...OPENAPI_COMPONENTS.parameters },
+      schemas: { ...spec.components?.schemas, ...OPENAPI_COMPONENTS.schemas },
+      responses: { ...spec.components?.responses, ...OPENAPI_COMPONENTS.responses },
+    },
+  };
+});
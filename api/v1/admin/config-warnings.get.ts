@@ -0,0 +1,43 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "List config warnings",
+    description: "Lists every project whose `mosaic.toml` used deprecated keys the last time it was synced by `projects:sync`.",
+    parameters: [
+      { $ref: "#/components/parameters/limit" },
+      { $ref: "#/components/parameters/cursor" },
+    ],
+    responses: {
+      "200": {
+        description: "A page of config warnings per project",
+        content: {
+          "application/json": {
+            schema: {
+              allOf: [
+                { $ref: "#/components/schemas/Page" },
+                {
+                  type: "object",
+                  properties: {
+                    items: { type: "array", items: { $ref: "#/components/schemas/StoredConfigWarnings" } },
+                  },
+                },
+              ],
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  return await getConfigWarningsPage(getPageOptions(event));
+});
@@ -0,0 +1,77 @@
+import type { InboxStatus } from "~/utils/inbox";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "Triage inbox item",
+    description: "Changes the status of an inbox item.",
+    parameters: [
+      {
+        in: "path",
+        name: "id",
+        required: true,
+        schema: { type: "string" },
+      },
+    ],
+    requestBody: {
+      required: true,
+      content: {
+        "application/json": {
+          schema: {
+            type: "object",
+            required: ["status"],
+            properties: {
+              status: { type: "string", enum: ["new", "acked", "resolved"] },
+            },
+          },
+        },
+      },
+    },
+    responses: {
+      "200": {
+        description: "The updated inbox item",
+        content: {
+          "application/json": {
+            schema: { $ref: "#/components/schemas/InboxItem" },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  const id = getRouterParam(event, "id");
+  const body = await readBody<{ status?: InboxStatus }>(event).catch(() => undefined);
+
+  if (!id || !body?.status || !INBOX_STATUSES.includes(body.status)) {
+    throw createApiError({
+      status: 400,
+      code: "invalid_request",
+      message: `missing id or unknown status, expected one of ${INBOX_STATUSES.join(", ")}`,
+    });
+  }
+
+  const [item] = await setInboxStatus([id], body.status);
+
+  if (!item) {
+    throw createApiError({
+      status: 404,
+      code: "not_found",
+      message: "inbox item not found",
+    });
+  }
+
+  return item;
+});
@@ -0,0 +1,110 @@
+import { z } from "zod";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "Bulk triage inbox",
+    description: "Acks, resolves, reopens or deletes several inbox items at once, either by id or by every item matching a filter. Targeting every item requires `all: true`.",
+    requestBody: {
+      required: true,
+      content: {
+        "application/json": {
+          schema: {
+            type: "object",
+            required: ["action"],
+            properties: {
+              action: { type: "string", enum: ["ack", "resolve", "reopen", "delete"] },
+              ids: { type: "array", items: { type: "string" } },
+              filter: {
+                type: "object",
+                properties: {
+                  status: { type: "array", items: { type: "string", enum: ["new", "acked", "resolved"] } },
+                  source: { type: "array", items: { type: "string", enum: ["report", "link_check", "job"] } },
+                  owner: { type: "string" },
+                  repository: { type: "string" },
+                },
+              },
+              all: {
+                type: "boolean",
+                enum: [true],
+                description: "Target every item, required instead of an empty filter.",
+              },
+            },
+          },
+        },
+      },
+    },
+    responses: {
+      "200": {
+        description: "The number of affected items",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["action", "affected"],
+              properties: {
+                action: { type: "string", enum: ["ack", "resolve", "reopen", "delete"] },
+                affected: { type: "integer", description: "The number of items the action was applied to." },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+const BULK_SCHEMA = z.object({
+  action: z.enum(["ack", "resolve", "reopen", "delete"]),
+  ids: z.array(z.string()).optional(),
+  filter: z.object({
+    status: z.array(z.enum(INBOX_STATUSES)).optional(),
+    source: z.array(z.enum(INBOX_SOURCES)).optional(),
+    owner: z.string().optional(),
+    repository: z.string().optional(),
+  }).optional(),
+  all: z.literal(true).optional(),
+}).refine((body) => {
+  // an empty filter matches every item, so wiping the inbox has to be asked for explicitly.
+  const filtered = body.filter != null && Object.values(body.filter).some((value) => value != null);
+  return body.ids != null || filtered || body.all === true;
+}, {
+  message: "either ids, a filter with at least one field, or all: true is required",
+});
+
+const ACTION_STATUSES = {
+  ack: "acked",
+  resolve: "resolved",
+  reopen: "new",
+} as const;
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  const result = BULK_SCHEMA.safeParse(await readBody(event).catch(() => undefined));
+
+  if (!result.success) {
+    throw createApiError({
+      status: 400,
+      code: "invalid_request",
+      message: "invalid bulk action",
+      data: result.error,
+    });
+  }
+
+  const { action, ids, filter } = result.data;
+  const targets = ids ?? (await getInboxItems(filter)).map((item) => item.id);
+
+  if (action === "delete") {
+    return { action, affected: await deleteInboxItems(targets) };
+  }
+
+  const updated = await setInboxStatus(targets, ACTION_STATUSES[action]);
+  return { action, affected: updated.length };
+});
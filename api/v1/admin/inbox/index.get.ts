@@ -0,0 +1,93 @@
+import type { InboxSource, InboxStatus } from "~/utils/inbox";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "List inbox",
+    description: "Lists the inbox of operational follow-ups, such as visitor reports, broken links and failed jobs, newest first.",
+    parameters: [
+      {
+        in: "query",
+        name: "status",
+        description: "Comma separated statuses to list, e.g. `new,acked`.",
+        schema: { type: "string" },
+      },
+      {
+        in: "query",
+        name: "source",
+        description: "Comma separated sources to list, e.g. `report,link_check`.",
+        schema: { type: "string" },
+      },
+      {
+        in: "query",
+        name: "owner",
+        schema: { type: "string" },
+      },
+      {
+        in: "query",
+        name: "repository",
+        schema: { type: "string" },
+      },
+      { $ref: "#/components/parameters/limit" },
+      { $ref: "#/components/parameters/cursor" },
+    ],
+    responses: {
+      "200": {
+        description: "A page of inbox items",
+        content: {
+          "application/json": {
+            schema: {
+              allOf: [
+                { $ref: "#/components/schemas/Page" },
+                {
+                  type: "object",
+                  properties: {
+                    items: { type: "array", items: { $ref: "#/components/schemas/InboxItem" } },
+                  },
+                },
+              ],
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+function parseList<T extends string>(value: unknown, allowed: readonly T[], name: string): T[] | undefined {
+  if (typeof value !== "string" || !value) {
+    return undefined;
+  }
+
+  const values = value.split(",").map((item) => item.trim());
+  const unknown = values.filter((item) => !allowed.includes(item as T));
+
+  if (unknown.length) {
+    throw createApiError({
+      status: 400,
+      code: "invalid_request",
+      message: `unknown ${name} ${unknown.join(", ")}, expected one of ${allowed.join(", ")}`,
+    });
+  }
+
+  return values as T[];
+}
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  const query = getQuery(event);
+
+  return await getInboxPage({
+    status: parseList<InboxStatus>(query.status, INBOX_STATUSES, "status"),
+    source: parseList<InboxSource>(query.source, INBOX_SOURCES, "source"),
+    owner: typeof query.owner === "string" ? query.owner : undefined,
+    repository: typeof query.repository === "string" ? query.repository : undefined,
+  }, getPageOptions(event));
+});
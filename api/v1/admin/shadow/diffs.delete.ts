@@ -0,0 +1,23 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "Clear shadow diffs",
+    description: "Removes every recorded shadow diff.",
+    responses: {
+      "204": {
+        description: "The diffs were removed",
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  await clearShadowDiffs();
+
+  return sendNoContent(event);
+});
@@ -0,0 +1,43 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "List shadow diffs",
+    description: "Lists the differences between production and staging responses, recorded while shadowing traffic.",
+    parameters: [
+      { $ref: "#/components/parameters/limit" },
+      { $ref: "#/components/parameters/cursor" },
+    ],
+    responses: {
+      "200": {
+        description: "A page of the recorded diffs, newest first",
+        content: {
+          "application/json": {
+            schema: {
+              allOf: [
+                { $ref: "#/components/schemas/Page" },
+                {
+                  type: "object",
+                  properties: {
+                    items: { type: "array", items: { $ref: "#/components/schemas/ShadowDiff" } },
+                  },
+                },
+              ],
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  return await getShadowDiffs(getPageOptions(event));
+});
@@ -0,0 +1,85 @@
+import type { OpenAPISpec } from "~/utils/spec-diff";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "Diff OpenAPI specs",
+    description: "Computes the added, removed and changed operations and schemas between two stored spec versions.",
+    parameters: [
+      {
+        in: "query",
+        name: "from",
+        required: true,
+        description: "The old version.",
+        schema: { type: "string" },
+      },
+      {
+        in: "query",
+        name: "to",
+        description: "The new version. Defaults to the currently served spec.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The diff",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["from", "to", "paths", "schemas"],
+              properties: {
+                from: { type: "string" },
+                to: { type: "string", description: "The version compared against, `current` for the live spec." },
+                paths: { $ref: "#/components/schemas/SpecChanges" },
+                schemas: { $ref: "#/components/schemas/SpecChanges" },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  const { from, to } = getQuery(event);
+
+  if (typeof from !== "string" || !from) {
+    throw createApiError({
+      status: 400,
+      code: "missing_params",
+      message: "missing from",
+    });
+  }
+
+  const fromSpec = await getSpec(from);
+  const toSpec = typeof to === "string" && to
+    ? await getSpec(to)
+    : await $fetch<OpenAPISpec>("/api/openapi.json");
+
+  if (!fromSpec || !toSpec) {
+    throw createApiError({
+      status: 404,
+      code: "not_found",
+      message: `no spec stored for ${fromSpec ? to : from}`,
+    });
+  }
+
+  return {
+    from,
+    to: typeof to === "string" && to ? to : "current",
+    ...diffSpecs(fromSpec, toSpec),
+  };
+});
@@ -0,0 +1,63 @@
+import type { OpenAPISpec } from "~/utils/spec-diff";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "Snapshot OpenAPI spec",
+    description: "Stores the currently served OpenAPI spec under a version, so it can be diffed against later releases.",
+    parameters: [
+      {
+        in: "query",
+        name: "version",
+        required: true,
+        description: "The version to store the spec under, e.g. `1.2.0`.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The spec was stored",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["version", "versions"],
+              properties: {
+                version: { type: "string" },
+                versions: { type: "array", description: "Every stored version.", items: { type: "string" } },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  const { version } = getQuery(event);
+
+  if (typeof version !== "string" || !version) {
+    throw createApiError({
+      status: 400,
+      code: "missing_params",
+      message: "missing version",
+    });
+  }
+
+  const spec = await $fetch<OpenAPISpec>("/api/openapi.json");
+  await saveSpec(version, spec);
+
+  return {
+    version,
+    versions: await getSpecVersions(),
+  };
+});
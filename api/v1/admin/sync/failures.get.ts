@@ -0,0 +1,54 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "List sync failures",
+    description: "Lists every project that failed to sync, with its last error, attempt count and next retry.",
+    parameters: [
+      { $ref: "#/components/parameters/limit" },
+      { $ref: "#/components/parameters/cursor" },
+      {
+        in: "query",
+        name: "deadLettered",
+        description: "Only list projects that are, or are not, dead-lettered.",
+        schema: { type: "boolean" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "A page of the sync failures",
+        content: {
+          "application/json": {
+            schema: {
+              allOf: [
+                { $ref: "#/components/schemas/Page" },
+                {
+                  type: "object",
+                  properties: {
+                    items: { type: "array", items: { $ref: "#/components/schemas/SyncFailure" } },
+                  },
+                },
+              ],
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  const { deadLettered } = getQuery(event);
+
+  return await getSyncFailures(
+    getPageOptions(event),
+    deadLettered === "true" || deadLettered === "false" ? deadLettered === "true" : undefined,
+  );
+});
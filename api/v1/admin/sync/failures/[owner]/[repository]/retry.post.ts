@@ -0,0 +1,113 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "Retry sync",
+    description: "Retries the failed or dead-lettered jobs of a project immediately, every job is retried by the job that failed. A failure is cleared if its retry succeeds.",
+    parameters: [
+      {
+        in: "path",
+        name: "owner",
+        required: true,
+        description: "The owner of the repository.",
+        schema: { type: "string" },
+      },
+      {
+        in: "path",
+        name: "repository",
+        required: true,
+        description: "The name of the repository.",
+        schema: { type: "string" },
+      },
+      {
+        in: "query",
+        name: "job",
+        description: "Only retry the failure of this job, e.g. `stack:sync`. Every failed job is retried by default.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The result of the retry of every failed job",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              description: "The result of every retried job, keyed by job.",
+              additionalProperties: {
+                type: "object",
+                required: ["ok"],
+                properties: {
+                  ok: { type: "boolean" },
+                  result: { description: "What the retry returned." },
+                  failure: { $ref: "#/components/schemas/SyncFailure" },
+                  message: { type: "string" },
+                },
+              },
+            },
+          },
+        },
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+// syncs a single project the way the job that failed does, so a retry repeats what failed.
+const RETRIES: Record<string, (owner: string, repository: string) => Promise<unknown>> = {
+  "projects:sync": syncProject,
+  "links:check": checkProjectLinks,
+  "stack:sync": syncProjectStack,
+};
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  const owner = getRouterParam(event, "owner");
+  const repository = getRouterParam(event, "repository");
+
+  if (!owner || !repository) {
+    throw createApiError({
+      status: 400,
+      code: "missing_params",
+      message: "missing params",
+    });
+  }
+
+  const { job } = getQuery(event);
+  const failures = (await getProjectSyncFailures(owner, repository))
+    .filter((failure) => typeof job !== "string" || failure.job === job);
+
+  if (!failures.length) {
+    throw createApiError({
+      status: 404,
+      code: "not_found",
+      message: `${owner}/${repository} has no sync failure${typeof job === "string" ? ` for ${job}` : ""}`,
+    });
+  }
+
+  const results: Record<string, unknown> = {};
+  for (const failure of failures) {
+    const retry = RETRIES[failure.job];
+
+    if (!retry) {
+      results[failure.job] = { ok: false, failure, message: `${failure.job} can't be retried for a single project` };
+      continue;
+    }
+
+    try {
+      const result = await retry(owner, repository);
+      await clearSyncFailure(failure.job, owner, repository);
+
+      results[failure.job] = { ok: true, result };
+    } catch (err) {
+      results[failure.job] = { ok: false, failure: await recordSyncFailure(failure.job, owner, repository, err) };
+    }
+  }
+
+  return results;
+});
@@ -0,0 +1,49 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "List sync jobs",
+    description: "Lists the last run of every sync job that has run, with its status and duration, and whether the scheduler is paused.",
+    parameters: [
+      { $ref: "#/components/parameters/limit" },
+      { $ref: "#/components/parameters/cursor" },
+    ],
+    responses: {
+      "200": {
+        description: "A page of the last runs",
+        content: {
+          "application/json": {
+            schema: {
+              allOf: [
+                { $ref: "#/components/schemas/SchedulerState" },
+                { $ref: "#/components/schemas/Page" },
+                {
+                  type: "object",
+                  properties: {
+                    items: { type: "array", items: { $ref: "#/components/schemas/JobRun" } },
+                  },
+                },
+              ],
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  const page = await getJobRuns(getPageOptions(event));
+
+  return {
+    paused: await isSchedulerPaused(),
+    ...page,
+  };
+});
@@ -0,0 +1,28 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "Pause scheduler",
+    description: "Pauses the scheduler. Scheduled jobs are skipped until it is resumed, manual runs still work.",
+    responses: {
+      "200": {
+        description: "The state of the scheduler",
+        content: {
+          "application/json": {
+            schema: { $ref: "#/components/schemas/SchedulerState" },
+          },
+        },
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  await setSchedulerPaused(true);
+
+  return { paused: true };
+});
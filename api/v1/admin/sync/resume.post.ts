@@ -0,0 +1,28 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "Resume scheduler",
+    description: "Resumes the scheduler.",
+    responses: {
+      "200": {
+        description: "The state of the scheduler",
+        content: {
+          "application/json": {
+            schema: { $ref: "#/components/schemas/SchedulerState" },
+          },
+        },
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  await setSchedulerPaused(false);
+
+  return { paused: false };
+});
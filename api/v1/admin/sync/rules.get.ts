@@ -0,0 +1,56 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "Preview sync rules",
+    description: "Lists every discovered repository, and whether the sync rules include it. Pass `rules` to preview rules before configuring them.",
+    parameters: [
+      {
+        in: "query",
+        name: "rules",
+        description: "Comma separated rules to preview instead of the configured ones, e.g. `luxass/*,!*-archive`.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The rules, and the repositories they include and exclude",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["rules", "included", "excluded"],
+              properties: {
+                rules: { type: "array", items: { type: "string" } },
+                included: { type: "array", items: { $ref: "#/components/schemas/SyncRuleMatch" } },
+                excluded: { type: "array", items: { $ref: "#/components/schemas/SyncRuleMatch" } },
+              },
+            },
+          },
+        },
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  const query = getQuery(event);
+  const rules = typeof query.rules === "string"
+    ? query.rules.split(",").map((rule) => rule.trim()).filter(Boolean)
+    : getSyncRules();
+
+  const repositories = (await listOwnerRepositories()).map((repository) => ({
+    nameWithOwner: repository.nameWithOwner,
+    ...evaluateSyncRules(rules, repository.nameWithOwner),
+  }));
+
+  return {
+    rules,
+    included: repositories.filter((repository) => repository.included),
+    excluded: repositories.filter((repository) => !repository.included),
+  };
+});
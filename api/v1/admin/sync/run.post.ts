@@ -0,0 +1,64 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["admin"],
+    summary: "Run sync",
+    description: "Runs a sync job immediately, even while the scheduler is paused. Runs every job when no job is given.",
+    parameters: [
+      {
+        in: "query",
+        name: "job",
+        description: "The name of the job, e.g. `links:check`.",
+        schema: { type: "string" },
+      },
+      {
+        in: "query",
+        name: "scheduled",
+        description: "Run the jobs as a scheduled run, which is skipped while the scheduler is paused. Used by external cron triggers.",
+        schema: { type: "boolean" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The results of the jobs",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              description: "The result of every job that was run, keyed by job.",
+              additionalProperties: {},
+            },
+          },
+        },
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  requireAdmin(event);
+
+  const { job, scheduled } = getQuery(event);
+
+  if (typeof job === "string" && !SYNC_JOBS.includes(job)) {
+    throw createApiError({
+      status: 400,
+      code: "invalid_request",
+      message: `unknown job, expected one of ${SYNC_JOBS.join(", ")}`,
+    });
+  }
+
+  const jobs = typeof job === "string" ? [job] : SYNC_JOBS;
+
+  // tasks treat runs with a `scheduledTime` as scheduled, like the ones started by `scheduledTasks`.
+  const payload = scheduled === "true" ? { scheduledTime: Date.now() } : {};
+
+  const results: Record<string, unknown> = {};
+  for (const name of jobs) {
+    results[name] = (await runTask(name, { payload })).result;
+  }
+
+  return results;
+});
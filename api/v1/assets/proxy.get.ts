@@ -0,0 +1,167 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["assets"],
+    summary: "Proxy image",
+    description: "Fetches, optionally resizes and re-serves an image from GitHub, cached for a day.",
+    parameters: [
+      {
+        in: "query",
+        name: "url",
+        required: true,
+        description: "The url of the image. Only GitHub user content hosts are allowed.",
+        schema: { type: "string" },
+      },
+      {
+        in: "query",
+        name: "w",
+        description: "The maximum width of the image.",
+        schema: { type: "integer", minimum: 1, maximum: 2048 },
+      },
+      {
+        in: "query",
+        name: "h",
+        description: "The maximum height of the image.",
+        schema: { type: "integer", minimum: 1, maximum: 2048 },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The image",
+        content: {
+          "image/*": {
+            schema: { type: "string", format: "binary" },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "502": {
+        $ref: "#/components/responses/BadGateway",
+      },
+    },
+  },
+});
+
+const ALLOWED_HOSTS = [
+  "raw.githubusercontent.com",
+  "user-images.githubusercontent.com",
+  "private-user-images.githubusercontent.com",
+  "camo.githubusercontent.com",
+  "avatars.githubusercontent.com",
+  "repository-images.githubusercontent.com",
+];
+
+// 10 MB
+const MAX_SIZE = 10 * 1024 * 1024;
+
+// images on `raw.githubusercontent.com` are served from a branch, so the same url can change.
+const ONE_DAY = 60 * 60 * 24;
+const ONE_WEEK = ONE_DAY * 7;
+
+function parseDimension(value: unknown): number | undefined {
+  const dimension = Number(value);
+  return Number.isInteger(dimension) && dimension > 0 ? Math.min(dimension, 2048) : undefined;
+}
+
+export default defineEventHandler(async (event) => {
+  const query = getQuery(event);
+
+  if (typeof query.url !== "string" || !query.url) {
+    throw createApiError({
+      status: 400,
+      code: "missing_params",
+      message: "missing url",
+    });
+  }
+
+  let url: URL;
+  try {
+    url = new URL(query.url);
+  } catch {
+    throw createApiError({
+      status: 400,
+      code: "invalid_request",
+      message: "url is not valid",
+    });
+  }
+
+  // images attached to issues and readmes are served from github.com/user-attachments
+  const allowed = url.protocol === "https:" && (
+    ALLOWED_HOSTS.includes(url.hostname)
+    || (url.hostname === "github.com" && url.pathname.startsWith("/user-attachments/"))
+  );
+
+  if (!allowed) {
+    throw createApiError({
+      status: 400,
+      code: "invalid_request",
+      message: `host ${url.hostname} is not allowed`,
+    });
+  }
+
+  const width = parseDimension(query.w);
+  const height = parseDimension(query.h);
+
+  const res = await fetch(url, {
+    // resizing and caching is done by cloudflare, outside of cloudflare these options are ignored.
+    // @ts-expect-error - `cf` is only known to the cloudflare runtime
+    cf: {
+      cacheEverything: true,
+      cacheTtl: ONE_DAY,
+      image: width || height ? { width, height, fit: "scale-down" } : undefined,
+    },
+    signal: AbortSignal.timeout(15_000),
+  }).catch(() => undefined);
+
+  if (!res || !res.ok || !res.body) {
+    throw createApiError({
+      status: 502,
+      code: "upstream_error",
+      message: `could not fetch image${res ? `, upstream responded with ${res.status}` : ""}`,
+    });
+  }
+
+  const contentType = res.headers.get("content-type") || "";
+
+  if (!contentType.startsWith("image/")) {
+    throw createApiError({
+      status: 400,
+      code: "invalid_request",
+      message: "url does not point to an image",
+    });
+  }
+
+  if (Number(res.headers.get("content-length") || 0) > MAX_SIZE) {
+    throw createApiError({
+      status: 400,
+      code: "invalid_request",
+      message: "image is too large",
+    });
+  }
+
+  // `content-length` can be missing or wrong, so the size is also enforced while streaming the body.
+  let size = 0;
+  const body = res.body.pipeThrough(new TransformStream<Uint8Array, Uint8Array>({
+    transform(chunk, controller) {
+      size += chunk.byteLength;
+
+      if (size > MAX_SIZE) {
+        controller.error(new Error(`image is larger than ${MAX_SIZE} bytes`));
+        return;
+      }
+
+      controller.enqueue(chunk);
+    },
+  }));
+
+  setResponseHeaders(event, {
+    "Content-Type": contentType,
+    "Cache-Control": `public, max-age=${ONE_DAY}, stale-while-revalidate=${ONE_WEEK}`,
+    // svgs can contain scripts, never let them run on our origin
+    "Content-Security-Policy": "default-src 'none'; style-src 'unsafe-inline'; sandbox",
+    "X-Content-Type-Options": "nosniff",
+  });
+
+  return body;
+});
@@ -1,5 +1,29 @@
 import { Octokit } from "@octokit/rest";
 
+defineRouteMeta({
+  openAPI: {
+    tags: ["events"],
+    summary: "List recent events",
+    description: "Lists the most recent public GitHub events of the configured user.",
+    responses: {
+      "200": {
+        description: "The recent events",
+        content: {
+          "application/json": {
+            schema: {
+              type: "array",
+              items: { $ref: "#/components/schemas/GitHubEvent" },
+            },
+          },
+        },
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
 export default defineLazyEventHandler(() => {
   const config = useRuntimeConfig();
   const octokit = new Octokit({
@@ -1,4 +1,45 @@
 import { Octokit } from "@octokit/rest";
+import type { Release } from "~/utils/releases";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["events"],
+    summary: "List recent releases",
+    description: "Lists the most recent releases of the configured user, including the download count of every release asset.",
+    responses: {
+      "200": {
+        description: "The recent releases",
+        content: {
+          "application/json": {
+            schema: {
+              type: "array",
+              items: {
+                allOf: [
+                  { $ref: "#/components/schemas/GitHubEvent" },
+                  {
+                    type: "object",
+                    required: ["title", "sha", "commit", "version", "assets", "downloads"],
+                    properties: {
+                      title: { type: "string", description: "The first line of the release commit." },
+                      sha: { type: "string" },
+                      commit: { type: "string", format: "uri" },
+                      version: { type: "string" },
+                      assets: { type: "array", items: { $ref: "#/components/schemas/ReleaseAsset" } },
+                      downloads: { type: "integer", description: "The total downloads of the release assets." },
+                    },
+                  },
+                ],
+              },
+            },
+          },
+        },
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
 
 export default defineLazyEventHandler(() => {
   const config = useRuntimeConfig();
@@ -51,7 +92,26 @@ export default defineLazyEventHandler(() => {
 
       events.reverse();
 
-      return events;
+      const releasesByRepo = new Map<string, Promise<Release[] | undefined>>();
+
+      return await Promise.all(events.map(async (event) => {
+        const [owner, repository] = event.repo.split("/");
+
+        if (!releasesByRepo.has(event.repo)) {
+          releasesByRepo.set(event.repo, getReleases(owner, repository));
+        }
+
+        const releases = await releasesByRepo.get(event.repo);
+        const release = releases?.find(
+          (release) => release.tag === event.version || release.tag === `v${event.version}`,
+        );
+
+        return {
+          ...event,
+          assets: release?.assets || [],
+          downloads: release?.downloads || 0,
+        };
+      }));
     },
     {
       maxAge: 60 * 5, // 5 minutes
@@ -0,0 +1,114 @@
+import type { BadgeOptions } from "~/utils/badge";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get badge",
+    description: "Renders a shields-style SVG badge for a project.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+      {
+        in: "path",
+        name: "kind",
+        required: true,
+        description: "The kind of badge, optionally suffixed with `.svg`.",
+        schema: { type: "string", enum: ["stars.svg", "version.svg", "language.svg", "license.svg"] },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The badge",
+        content: {
+          "image/svg+xml": {
+            schema: { type: "string" },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+const BADGE_KINDS = ["stars", "version", "language", "license"] as const;
+
+type BadgeKind = typeof BADGE_KINDS[number];
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+    const kind = getRouterParam(event, "kind")?.replace(/\.svg$/, "") as BadgeKind | undefined;
+
+    if (!username || !repositoryName || !kind) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    if (!BADGE_KINDS.includes(kind)) {
+      throw createApiError({
+        status: 400,
+        code: "invalid_request",
+        message: `unknown badge kind, expected one of ${BADGE_KINDS.join(", ")}`,
+      });
+    }
+
+    const repository = await getRepository(username, repositoryName);
+
+    if (!repository) {
+      throw createApiError({
+        status: 404,
+        code: "project_not_found",
+        message: "repository not found",
+      });
+    }
+
+    let badge: BadgeOptions;
+
+    switch (kind) {
+      case "stars":
+        badge = { label: "stars", message: formatCount(repository.stargazerCount), color: "#dfb317" };
+        break;
+      case "version": {
+        const tag = await getLatestTag(username, repositoryName);
+        badge = tag
+          ? { label: "version", message: tag.startsWith("v") ? tag : `v${tag}`, color: "#007ec6" }
+          : { label: "version", message: "none", color: "#9f9f9f" };
+        break;
+      }
+      case "language": {
+        const language = repository.languages?.nodes?.[0];
+        badge = language
+          ? { label: "language", message: language.name, color: language.color || "#007ec6" }
+          : { label: "language", message: "none", color: "#9f9f9f" };
+        break;
+      }
+      case "license":
+        badge = repository.licenseInfo
+          ? { label: "license", message: repository.licenseInfo.spdxId || repository.licenseInfo.name, color: "#97ca00" }
+          : { label: "license", message: "none", color: "#9f9f9f" };
+        break;
+    }
+
+    setResponseHeaders(event, {
+      "Content-Type": "image/svg+xml; charset=utf-8",
+      "Cache-Control": "public, max-age=3600, s-maxage=3600, stale-while-revalidate=86400",
+    });
+
+    return renderBadge(badge);
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
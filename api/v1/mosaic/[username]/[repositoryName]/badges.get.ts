@@ -0,0 +1,101 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Check README badges",
+    description: "Checks that every badge image in the README resolves without rendering an error.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The badge checks",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["lastChecked", "broken", "badges"],
+              properties: {
+                lastChecked: { type: "string", format: "date-time" },
+                broken: { type: "integer" },
+                badges: {
+                  type: "array",
+                  items: {
+                    type: "object",
+                    required: ["url", "ok"],
+                    properties: {
+                      url: { type: "string", format: "uri" },
+                      ok: { type: "boolean" },
+                      status: { type: "integer" },
+                      reason: { type: "string", description: "Why the badge is broken." },
+                    },
+                  },
+                },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const readme = await getREADME({
+      owner: username,
+      repository: repositoryName,
+    });
+
+    if (!readme || readme.type === "not_found") {
+      throw createApiError({
+        status: 404,
+        code: "readme_not_found",
+        message: "repository has no readme defined",
+      });
+    }
+
+    if (readme.type === "error") {
+      throw createApiError({
+        status: 500,
+        code: "readme_error",
+        message: "error resolving readme",
+        data: readme.details,
+      });
+    }
+
+    const badges = await Promise.all(extractBadgeUrls(readme.content).map(checkBadge));
+
+    return {
+      lastChecked: new Date().toISOString(),
+      broken: badges.filter((badge) => !badge.ok).length,
+      badges,
+    };
+  }, {
+    maxAge: 60 * 60 * 6, // 6 hours
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
@@ -0,0 +1,62 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get project card",
+    description: "Returns a lightweight payload for rendering an embeddable project card.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+      { $ref: "#/components/parameters/lang" },
+    ],
+    responses: {
+      "200": {
+        description: "The project card",
+        content: {
+          "application/json": {
+            schema: { $ref: "#/components/schemas/ProjectCard" },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const card = await getProjectCard(username, repositoryName, getRequestLocales(event));
+
+    if (!card) {
+      throw createApiError({
+        status: 404,
+        code: "project_not_found",
+        message: "repository not found",
+      });
+    }
+
+    return card;
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    ...LOCALIZED_CACHE_OPTIONS,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
@@ -0,0 +1,145 @@
+import type { PolicyCheck } from "~/utils/policy";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get onboarding checklist",
+    description: "Computes a completeness checklist for a project, including its repository policy, with links to fix every missing item.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The checklist",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["completed", "total", "complete", "items"],
+              properties: {
+                completed: { type: "integer" },
+                total: { type: "integer" },
+                complete: { type: "boolean" },
+                items: { type: "array", items: { $ref: "#/components/schemas/ChecklistItem" } },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+interface ChecklistItem {
+  id: "description" | "logo" | "topics" | "docs" | "og-image" | "handle" | PolicyCheck["id"];
+  label: string;
+  done: boolean;
+  fix: string;
+}
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const resolvedMosaicConfig = await requireResolvedConfig(username, repositoryName);
+
+    if (resolvedMosaicConfig.content.project.ignore) {
+      throw createApiError({
+        status: 404,
+        code: "project_ignored",
+        message: "repository is ignored",
+      });
+    }
+
+    const repository = await getRepository(username, repositoryName);
+
+    if (!repository) {
+      throw createApiError({
+        status: 404,
+        code: "project_not_found",
+        message: "repository not found",
+      });
+    }
+
+    const config = resolvedMosaicConfig.content;
+    const [readme, policy] = await Promise.all([
+      getREADME({ owner: username, repository: repositoryName }),
+      getRepositoryPolicy(username, repositoryName),
+    ]);
+
+    const settingsUrl = `${repository.url}/settings`;
+
+    const items: ChecklistItem[] = [
+      {
+        id: "description",
+        label: "has a description",
+        done: !!(config.project.description || repository.description),
+        fix: settingsUrl,
+      },
+      {
+        id: "logo",
+        label: "has a logo in the README",
+        // a logo is usually the first image in the README, named or described as a logo
+        done: readme.type === "resolved" && /!\[[^\]]*logo[^\]]*\]|<img[^>]*logo/i.test(readme.content),
+        fix: `${repository.url}/edit/${repository.defaultBranchRef?.name || "main"}/README.md`,
+      },
+      {
+        id: "topics",
+        label: "has topics",
+        done: !!repository.repositoryTopics.nodes?.length,
+        fix: settingsUrl,
+      },
+      // the license, code of conduct, security policy and branch protection, as checked by the policy route.
+      ...policy?.checks ?? [],
+      {
+        id: "docs",
+        label: "has a docs or website url",
+        done: !!((config.website?.enabled && config.website.url) || repository.homepageUrl),
+        fix: settingsUrl,
+      },
+      {
+        id: "og-image",
+        label: "has a custom social preview image",
+        done: repository.usesCustomOpenGraphImage,
+        fix: settingsUrl,
+      },
+      {
+        id: "handle",
+        label: "has a handle",
+        done: !!config.project.handle,
+        fix: resolvedMosaicConfig.path,
+      },
+    ];
+
+    const completed = items.filter((item) => item.done).length;
+
+    return {
+      completed,
+      total: items.length,
+      complete: completed === items.length,
+      items,
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
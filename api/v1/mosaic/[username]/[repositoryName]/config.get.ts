@@ -1,31 +1,54 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get project config",
+    description: "Resolves the `mosaic.toml` config of a repository.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The resolved config",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["lastModified", "content", "external", "path"],
+              properties: {
+                lastModified: { type: "string", format: "date-time" },
+                content: { $ref: "#/components/schemas/MosaicConfig" },
+                external: { type: "boolean", description: "Whether the config is stored outside of the repository." },
+                path: { type: "string", description: "The url of the config." },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
 export default defineLazyEventHandler(async () => {
   return defineCachedEventHandler(async (event) => {
     const username = getRouterParam(event, "username");
     const repositoryName = getRouterParam(event, "repositoryName");
 
     if (!username || !repositoryName) {
-      throw createError({
+      throw createApiError({
         status: 400,
+        code: "missing_params",
         message: "missing params",
       });
     }
 
-    const resolvedMosaicConfig = await resolveMosaicConfig(username, repositoryName);
-
-    if (!resolvedMosaicConfig || resolvedMosaicConfig.type === "not_found") {
-      throw createError({
-        status: 404,
-        message: "repository has no config defined",
-      });
-    }
-
-    if (resolvedMosaicConfig.type === "error") {
-      throw createError({
-        status: 400,
-        message: "error resolving config due to config not being valid",
-        data: resolvedMosaicConfig.details,
-      });
-    }
+    const resolvedMosaicConfig = await requireResolvedConfig(username, repositoryName);
 
     return {
       lastModified: new Date().toISOString(),
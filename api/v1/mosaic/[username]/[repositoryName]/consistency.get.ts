@@ -0,0 +1,153 @@
+import type { Registry } from "~/utils/registry";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Check version consistency",
+    description: "Checks that the manifest version, the latest git tag and the latest published version agree.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The consistency diagnostics",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["consistent", "versions", "diagnostics"],
+              properties: {
+                consistent: { type: "boolean" },
+                registry: { type: "string", enum: ["npm", "crates"] },
+                versions: {
+                  type: "object",
+                  properties: {
+                    manifest: { type: "string" },
+                    tag: { type: "string" },
+                    registry: { type: "string" },
+                  },
+                },
+                diagnostics: { type: "array", items: { $ref: "#/components/schemas/Diagnostic" } },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+interface Diagnostic {
+  level: "warning" | "error";
+  message: string;
+}
+
+// tags can be prefixed with `v` or the package name (e.g. `v1.0.0` or `pkg@1.0.0`)
+function normalizeVersion(version: string): string {
+  return version.replace(/^.*@/, "").replace(/^v/, "");
+}
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const resolvedMosaicConfig = await requireResolvedConfig(username, repositoryName);
+
+    const config = resolvedMosaicConfig.content;
+
+    let registry: Registry | undefined;
+    let manifestVersion: string | undefined;
+    let registryVersion: string | undefined;
+
+    if (config.npm?.enabled) {
+      registry = "npm";
+      const pkg = await getPackage(username, repositoryName);
+      manifestVersion = pkg.version;
+
+      const name = config.npm.name || pkg.name;
+      registryVersion = name ? await getNpmLatestVersion(name) : undefined;
+    } else {
+      const manifest = await getCargoManifest(username, repositoryName);
+
+      if (manifest?.package) {
+        registry = "crates";
+        manifestVersion = getCargoPackageVersion(manifest);
+        registryVersion = manifest.package.publish !== false
+          ? (await getCrateVersions(manifest.package.name))?.[0]?.version
+          : undefined;
+      }
+    }
+
+    const tag = await getLatestTag(username, repositoryName);
+
+    const diagnostics: Diagnostic[] = [];
+
+    if (!registry) {
+      diagnostics.push({
+        level: "warning",
+        message: "no package manifest found, only the latest tag could be resolved",
+      });
+    }
+
+    if (registry && !registryVersion) {
+      diagnostics.push({
+        level: "warning",
+        message: `no published version found on ${registry}`,
+      });
+    }
+
+    if (tag && registryVersion && normalizeVersion(tag) !== normalizeVersion(registryVersion)) {
+      diagnostics.push({
+        level: "error",
+        message: `tag ${tag} exists but ${registry} has ${registryVersion}`,
+      });
+    }
+
+    if (manifestVersion && tag && normalizeVersion(manifestVersion) !== normalizeVersion(tag)) {
+      diagnostics.push({
+        level: "error",
+        message: `manifest has version ${manifestVersion} but the latest tag is ${tag}`,
+      });
+    }
+
+    if (manifestVersion && registryVersion && normalizeVersion(manifestVersion) !== normalizeVersion(registryVersion)) {
+      diagnostics.push({
+        level: "error",
+        message: `manifest has version ${manifestVersion} but ${registry} has ${registryVersion}`,
+      });
+    }
+
+    return {
+      consistent: !diagnostics.some((diagnostic) => diagnostic.level === "error"),
+      registry,
+      versions: {
+        manifest: manifestVersion,
+        tag,
+        registry: registryVersion,
+      },
+      diagnostics,
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
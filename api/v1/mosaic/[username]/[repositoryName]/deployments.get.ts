@@ -0,0 +1,105 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "List website deployments",
+    description: "Lists the latest Vercel deployments of the project website.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The deployments",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["project", "broken", "deployments"],
+              properties: {
+                project: { type: "string", description: "The name or id of the Vercel project." },
+                broken: { type: "boolean", description: "Whether the latest production deployment failed." },
+                deployments: {
+                  type: "array",
+                  items: {
+                    type: "object",
+                    required: ["id", "createdAt"],
+                    properties: {
+                      id: { type: "string" },
+                      url: { type: "string", format: "uri" },
+                      state: { type: "string", enum: ["BUILDING", "ERROR", "INITIALIZING", "QUEUED", "READY", "CANCELED", "DELETED"] },
+                      target: { type: "string" },
+                      createdAt: { type: "string", format: "date-time" },
+                      inspectorUrl: { type: "string", format: "uri" },
+                    },
+                  },
+                },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+      "502": {
+        $ref: "#/components/responses/BadGateway",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const resolvedMosaicConfig = await requireResolvedConfig(username, repositoryName);
+
+    const website = resolvedMosaicConfig.content.website;
+
+    if (!website?.enabled || !website.vercel) {
+      throw createApiError({
+        status: 404,
+        code: "deployment_not_found",
+        message: "repository has no vercel project linked",
+      });
+    }
+
+    // the latest deployments can all be previews, so the production status is queried separately.
+    const [deployments, production] = await Promise.all([
+      getVercelDeployments(website.vercel),
+      getVercelDeployments(website.vercel, { target: "production", limit: 1 }),
+    ]);
+
+    if (!deployments || !production) {
+      throw createApiError({
+        status: 502,
+        code: "vercel_unavailable",
+        message: "could not fetch deployments from vercel",
+      });
+    }
+
+    return {
+      project: website.vercel,
+      broken: isDeploymentBroken(production),
+      deployments,
+    };
+  }, {
+    maxAge: 60 * 5, // 5 minutes
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
@@ -0,0 +1,115 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["readme"],
+    summary: "Get doc",
+    description: "Resolves a documentation page configured in `readme.docs`.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+      {
+        in: "path",
+        name: "slug",
+        required: true,
+        description: "The slug of the page.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The documentation page",
+        content: {
+          "application/json": {
+            schema: {
+              allOf: [
+                { $ref: "#/components/schemas/Readme" },
+                {
+                  type: "object",
+                  required: ["slug", "title"],
+                  properties: {
+                    slug: { type: "string" },
+                    title: { type: "string" },
+                  },
+                },
+              ],
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+    const slug = getRouterParam(event, "slug");
+
+    if (!username || !repositoryName || !slug) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const resolvedMosaicConfig = await requireResolvedConfig(username, repositoryName);
+
+    const readme = resolvedMosaicConfig.content.readme;
+    const page = getDocPages(readme?.enabled ? readme : undefined).find((page) => page.slug === slug);
+
+    if (!page) {
+      throw createApiError({
+        status: 404,
+        code: "readme_not_found",
+        message: `repository has no doc named ${slug}`,
+      });
+    }
+
+    const doc = await getREADME({
+      owner: username,
+      repository: repositoryName,
+      readmePath: page.path,
+    });
+
+    if (doc.type === "not_found") {
+      throw createApiError({
+        status: 404,
+        code: "readme_not_found",
+        message: `${page.path} does not exist in the repository`,
+      });
+    }
+
+    if (doc.type === "error") {
+      throw createApiError({
+        status: 500,
+        code: "readme_error",
+        message: "error resolving doc",
+        data: doc.details,
+      });
+    }
+
+    return {
+      lastModified: new Date().toISOString(),
+      slug: page.slug,
+      title: page.title || inferTitle(doc.content),
+      content: doc.content,
+      path: doc.path,
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
@@ -0,0 +1,62 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["readme"],
+    summary: "List docs",
+    description: "Lists the documentation pages configured in `readme.docs`.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The documentation pages",
+        content: {
+          "application/json": {
+            schema: {
+              type: "array",
+              items: { $ref: "#/components/schemas/DocPage" },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  const runtimeConfig = useRuntimeConfig();
+
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const resolvedMosaicConfig = await requireResolvedConfig(username, repositoryName);
+
+    const readme = resolvedMosaicConfig.content.readme;
+
+    return getDocPages(readme?.enabled ? readme : undefined).map((page) => ({
+      ...page,
+      url: `${runtimeConfig.siteUrl}/api/v1/mosaic/${username}/${repositoryName}/docs/${page.slug}`,
+    }));
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
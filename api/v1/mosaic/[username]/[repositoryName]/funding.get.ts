@@ -0,0 +1,71 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get project funding",
+    description: "Returns the funding links of a project from its `FUNDING.yml`, and whether the owner has a GitHub Sponsors profile, as stored by the `projects:sync` task.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The funding of the project",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["owner", "repository", "sponsorable", "links", "updatedAt"],
+              properties: {
+                owner: { type: "string" },
+                repository: { type: "string" },
+                sponsorable: { type: "boolean", description: "Whether the owner has a GitHub Sponsors profile." },
+                links: { type: "array", items: { $ref: "#/components/schemas/FundingLink" } },
+                updatedAt: { type: "string", format: "date-time" },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    // funding is resolved by the `projects:sync` task.
+    const funding = await getProjectFunding(username, repositoryName);
+
+    if (!funding) {
+      throw createApiError({
+        status: 404,
+        code: "not_found",
+        message: "no funding has been synced for this project",
+      });
+    }
+
+    return funding;
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
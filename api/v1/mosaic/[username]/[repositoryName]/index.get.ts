@@ -3,6 +3,41 @@ import { minimatch } from "minimatch";
 import { z } from "zod";
 import type { ResolvedProject } from "~/types";
 
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get projects",
+    description: "Resolves the projects defined by a repository, including every workspace package.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+      { $ref: "#/components/parameters/lang" },
+    ],
+    responses: {
+      "200": {
+        description: "The resolved projects",
+        content: {
+          "application/json": {
+            schema: {
+              type: "array",
+              items: { $ref: "#/components/schemas/ResolvedProject" },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
 export default defineLazyEventHandler(async () => {
   const runtimeConfig = useRuntimeConfig();
 
@@ -24,34 +59,22 @@ export default defineLazyEventHandler(async () => {
     const repositoryName = getRouterParam(event, "repositoryName");
 
     if (!username || !repositoryName) {
-      throw createError({
+      throw createApiError({
         status: 400,
+        code: "missing_params",
         message: "missing params",
       });
     }
 
-    const resolvedMosaicConfig = await resolveMosaicConfig(username, repositoryName);
-
-    if (!resolvedMosaicConfig || resolvedMosaicConfig.type === "not_found") {
-      throw createError({
-        status: 404,
-        message: "repository has no config defined",
-      });
-    }
-
-    if (resolvedMosaicConfig.type === "error") {
-      throw createError({
-        status: 400,
-        message: `could not resolve config for ${username}/${repositoryName} due to config not being valid`,
-        data: resolvedMosaicConfig.details,
-      });
-    }
+    const locales = getRequestLocales(event);
+    const resolvedMosaicConfig = await requireResolvedConfig(username, repositoryName);
 
     const repository = await getRepository(username, repositoryName);
 
     if (!repository) {
-      throw createError({
+      throw createApiError({
         status: 404,
+        code: "project_not_found",
         message: "repository not found",
       });
     }
@@ -59,12 +82,22 @@ export default defineLazyEventHandler(async () => {
     const config = resolvedMosaicConfig.content;
 
     if (config.project.ignore) {
-      throw createError({
+      throw createApiError({
         status: 404,
+        code: "project_ignored",
         message: "repository is ignored",
       });
     }
 
+    // the license and funding are resolved by the `projects:sync` task, github is only used until it has run.
+    const storedLicense = await getProjectLicense(username, repositoryName);
+    const githubLicense = repository.licenseInfo?.spdxId;
+    const license = config.project.license
+      || storedLicense?.spdxId
+      || (githubLicense && githubLicense !== "NOASSERTION" ? githubLicense : undefined);
+    const funding = await getProjectFunding(username, repositoryName) ?? collectProjectFunding(repository);
+    const availableLocales = getAvailableLocales(config.project.description, config.website?.title, config.website?.description);
+
     const projects: ResolvedProject[] = [];
 
     if (config.workspace && config.workspace.enabled) {
@@ -80,16 +113,9 @@ export default defineLazyEventHandler(async () => {
 
       const workspaces = rootPkg.workspaces;
 
-      const filesResult = await fetch(
-        `https://api.github.com/repos/${username}/${repositoryName}/git/trees/main?recursive=1`,
-        {
-          headers: {
-            "Authorization": `Bearer ${runtimeConfig.github.token}`,
-            "Content-Type": "application/vnd.github+json",
-            "X-GitHub-Api-Version": "2022-11-28",
-          },
-        },
-      ).then((res) => res.json());
+      const filesResult = await githubFetch(`/repos/${username}/${repositoryName}/git/trees/main?recursive=1`, {
+        token: getOwnerToken(username),
+      });
 
       if (!filesResult || typeof filesResult !== "object") {
         throw new Error(
@@ -149,13 +175,24 @@ export default defineLazyEventHandler(async () => {
           continue;
         }
 
+        const packageLocales = override?.project.description != null
+          ? getAvailableLocales(override.project.description, config.website?.title, config.website?.description)
+          : availableLocales;
+
         const project: ResolvedProject = {
           name: pkg.name,
+          owner: repository.owner.login,
           ignore: override?.project.ignore || config.project.ignore || false,
           deprecated: override?.deprecated || config.deprecated,
           stars: (override?.project.stars || config.project.stars) ? repository.stargazerCount : undefined,
+          downloads: (override?.project.downloads || config.project.downloads)
+            ? await getReleaseDownloads(username, repositoryName)
+            : undefined,
           priority: override?.project.priority || config.project.priority || 0,
-          description: config.project.description || repository.description || undefined,
+          description: localize(override?.project.description ?? config.project.description, locales) || repository.description || undefined,
+          locales: packageLocales.length ? packageLocales : undefined,
+          license: override?.project.license || license,
+          funding: funding.links.length ? funding.links : undefined,
         };
 
         if (config.website?.enabled) {
@@ -169,10 +206,14 @@ export default defineLazyEventHandler(async () => {
 
           project.website = {
             url: website,
-            title: config.website.title || repository.name,
-            description: config.website.description || project.description || undefined,
+            title: localize(config.website.title, locales) || repository.name,
+            description: localize(config.website.description, locales) || project.description || undefined,
             keywords: config.website.keywords || undefined,
           };
+
+          if (config.website.vercel) {
+            project.website.deployment = await getWebsiteDeployment(config.website.vercel);
+          }
         }
 
         const readme = override?.readme || config.readme;
@@ -222,16 +263,16 @@ export default defineLazyEventHandler(async () => {
         }
 
         if (override?.project.version || config.project.version) {
-          const latestReleaseResponse = await fetch(
-            `https://api.github.com/repos/${username}/${repositoryName}/releases/latest`,
-          );
+          const latestRelease = await githubFetchOptional(`/repos/${username}/${repositoryName}/releases/latest`, {
+            token: getOwnerToken(username),
+          });
           const pkgObj = await getPackage(username, repositoryName, pkg.path);
 
-          if (!latestReleaseResponse.ok && !pkgObj.version) {
+          if (!latestRelease && !pkgObj.version) {
             throw new Error(`could not find latest release on github and no version was found in package.json in ${pkg.name}`);
           }
 
-          if (!latestReleaseResponse.ok && pkgObj.version) {
+          if (!latestRelease && pkgObj.version) {
             console.warn("no latest release found on github");
             const npmResult = await fetch(`https://registry.npmjs.org/${pkgObj.name}`).then((res) => res.json());
 
@@ -251,7 +292,7 @@ export default defineLazyEventHandler(async () => {
 
             project.version = latestVersion || pkgObj.version;
           } else {
-            const result = await latestReleaseResponse.json();
+            const result = latestRelease;
 
             if (!result || typeof result !== "object" || !("tag_name" in result) || typeof result.tag_name !== "string") {
               throw new Error(
@@ -268,6 +309,7 @@ export default defineLazyEventHandler(async () => {
     } else {
       const project: ResolvedProject = {
         name: repository.name,
+        owner: repository.owner.login,
         ignore: config.project.ignore || false,
         priority: config.project.priority || 0,
         readme: config.readme
@@ -276,7 +318,11 @@ export default defineLazyEventHandler(async () => {
           : undefined,
         deprecated: config.deprecated,
         stars: config.project.stars ? repository.stargazerCount : undefined,
-        description: config.project.description || repository.description || undefined,
+        downloads: config.project.downloads ? await getReleaseDownloads(username, repositoryName) : undefined,
+        description: localize(config.project.description, locales) || repository.description || undefined,
+        locales: availableLocales.length ? availableLocales : undefined,
+        license,
+        funding: funding.links.length ? funding.links : undefined,
       };
       if (config.website?.enabled) {
         let website;
@@ -289,10 +335,14 @@ export default defineLazyEventHandler(async () => {
 
         project.website = {
           url: website,
-          title: config.website.title || repository.name,
-          description: config.website.description || project.description || undefined,
+          title: localize(config.website.title, locales) || repository.name,
+          description: localize(config.website.description, locales) || project.description || undefined,
           keywords: config.website.keywords || undefined,
         };
+
+        if (config.website.vercel) {
+          project.website.deployment = await getWebsiteDeployment(config.website.vercel);
+        }
       }
 
       if (config.npm?.enabled) {
@@ -335,16 +385,16 @@ export default defineLazyEventHandler(async () => {
       }
 
       if (config.project.version) {
-        const latestReleaseResponse = await fetch(
-          `https://api.github.com/repos/${username}/${repositoryName}/releases/latest`,
-        );
+        const latestRelease = await githubFetchOptional(`/repos/${username}/${repositoryName}/releases/latest`, {
+          token: getOwnerToken(username),
+        });
         const pkg = await getPackage(username, repositoryName);
 
-        if (!latestReleaseResponse.ok && !pkg.version) {
+        if (!latestRelease && !pkg.version) {
           throw new Error(`could not find latest release on github and no version was found in package.json in ${repository.name}`);
         }
 
-        if (!latestReleaseResponse.ok && pkg.version) {
+        if (!latestRelease && pkg.version) {
           console.warn("no latest release found on github");
           const npmResult = await fetch(`https://registry.npmjs.org/${pkg.name}`).then((res) => res.json());
 
@@ -364,7 +414,7 @@ export default defineLazyEventHandler(async () => {
 
           project.version = latestVersion || pkg.version;
         } else {
-          const result = await latestReleaseResponse.json();
+          const result = latestRelease;
 
           if (!result || typeof result !== "object" || !("tag_name" in result) || typeof result.tag_name !== "string") {
             throw new Error(
@@ -383,6 +433,7 @@ export default defineLazyEventHandler(async () => {
   }, {
     maxAge: 60 * 60, // 1 hour
     swr: true,
+    ...LOCALIZED_CACHE_OPTIONS,
     shouldBypassCache() {
       return import.meta.dev || false;
     },
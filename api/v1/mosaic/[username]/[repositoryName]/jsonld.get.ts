@@ -0,0 +1,135 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get JSON-LD",
+    description: "Returns schema.org `SoftwareSourceCode` structured data for a project, and `SoftwareApplication` when it has a website.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+      { $ref: "#/components/parameters/lang" },
+    ],
+    responses: {
+      "200": {
+        description: "The structured data",
+        content: {
+          "application/ld+json": {
+            schema: { type: "object" },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const resolvedMosaicConfig = await requireResolvedConfig(username, repositoryName);
+
+    const config = resolvedMosaicConfig.content;
+
+    // ignored projects are not shown on `luxass.dev`, so they shouldn't be indexed either.
+    if (config.project.ignore) {
+      throw createApiError({
+        status: 404,
+        code: "project_ignored",
+        message: "repository is ignored",
+      });
+    }
+
+    const repository = await getRepository(username, repositoryName);
+
+    if (!repository) {
+      throw createApiError({
+        status: 404,
+        code: "project_not_found",
+        message: "repository not found",
+      });
+    }
+    const locales = getRequestLocales(event);
+    const description = localize(config.project.description, locales) || repository.description || undefined;
+    const website = (config.website?.enabled && config.website.url) || repository.homepageUrl || undefined;
+    const version = config.project.version ? await getLatestTag(username, repositoryName) : undefined;
+    const keywords = [
+      ...(config.website?.keywords || []),
+      ...(repository.repositoryTopics.nodes || []).flatMap((node) => node ? [node.topic.name] : []),
+    ];
+
+    const author = {
+      "@type": "Person",
+      "name": username,
+      "url": `https://github.com/${username}`,
+    };
+
+    const sourceCode = {
+      "@type": "SoftwareSourceCode",
+      "name": config.project.name,
+      description,
+      "codeRepository": repository.url,
+      "url": website || repository.url,
+      "programmingLanguage": repository.languages?.nodes?.[0]?.name,
+      "license": config.project.license
+        ? `https://spdx.org/licenses/${config.project.license}.html`
+        : repository.licenseInfo?.url || undefined,
+      "version": version?.replace(/^v/, ""),
+      "dateModified": repository.pushedAt,
+      "keywords": keywords.length ? [...new Set(keywords)].join(", ") : undefined,
+      "image": repository.openGraphImageUrl,
+      author,
+    };
+
+    setResponseHeader(event, "Content-Type", "application/ld+json");
+
+    if (!website) {
+      return {
+        "@context": "https://schema.org",
+        ...sourceCode,
+      };
+    }
+
+    return {
+      "@context": "https://schema.org",
+      "@graph": [
+        sourceCode,
+        {
+          "@type": "SoftwareApplication",
+          "name": localize(config.website?.title, locales) || config.project.name,
+          "description": localize(config.website?.description, locales) || description,
+          "url": website,
+          "applicationCategory": "DeveloperApplication",
+          "softwareVersion": sourceCode.version,
+          "image": repository.openGraphImageUrl,
+          author,
+          "offers": {
+            "@type": "Offer",
+            "price": "0",
+            "priceCurrency": "USD",
+          },
+        },
+      ],
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    ...LOCALIZED_CACHE_OPTIONS,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
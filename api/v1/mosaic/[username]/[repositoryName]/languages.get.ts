@@ -1,3 +1,34 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get project languages",
+    description: "Lists the languages used in a repository.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The languages",
+        content: {
+          "application/json": {
+            schema: {
+              type: "array",
+              items: { $ref: "#/components/schemas/Language" },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
 export default defineLazyEventHandler(async () => {
   const ALL_LANGUAGES = await import("@luxass/github-languages/json").then((m) => m.default);
 
@@ -6,8 +37,9 @@ export default defineLazyEventHandler(async () => {
     const repositoryName = getRouterParam(event, "repositoryName");
 
     if (!username || !repositoryName) {
-      throw createError({
+      throw createApiError({
         status: 400,
+        code: "missing_params",
         message: "missing params",
       });
     }
@@ -15,8 +47,9 @@ export default defineLazyEventHandler(async () => {
     const languages = await getRepositoryLanguages(username, repositoryName);
 
     if (!languages) {
-      throw createError({
+      throw createApiError({
         status: 404,
+        code: "project_not_found",
         message: "repository not found",
       });
     }
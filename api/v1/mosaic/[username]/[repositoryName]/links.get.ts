@@ -0,0 +1,96 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get link checks",
+    description: "Returns the latest check of the website, README and docs links of a project. Links are re-checked daily, admins can re-check them immediately with `?refresh=true`.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+      {
+        in: "query",
+        name: "refresh",
+        description: "Re-check the links before returning them, requires the admin token.",
+        schema: { type: "boolean" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The link checks",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["owner", "repository", "checkedAt", "broken", "links"],
+              properties: {
+                owner: { type: "string" },
+                repository: { type: "string" },
+                checkedAt: { type: "string", format: "date-time" },
+                broken: { type: "integer" },
+                links: {
+                  type: "array",
+                  items: {
+                    type: "object",
+                    required: ["url", "source", "ok"],
+                    properties: {
+                      url: { type: "string", format: "uri" },
+                      source: { type: "string", enum: ["website", "readme", "docs", "badge"] },
+                      ok: { type: "boolean" },
+                      status: { type: "integer" },
+                      reason: { type: "string", description: "Why the link is broken." },
+                    },
+                  },
+                },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "401": {
+        $ref: "#/components/responses/Unauthorized",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  const username = getRouterParam(event, "username");
+  const repositoryName = getRouterParam(event, "repositoryName");
+
+  if (!username || !repositoryName) {
+    throw createApiError({
+      status: 400,
+      code: "missing_params",
+      message: "missing params",
+    });
+  }
+
+  const { refresh } = getQuery(event);
+
+  // checking links fans out to every linked host, so only admins can trigger it.
+  if (refresh === "true") {
+    requireAdmin(event);
+  }
+
+  const checks = refresh === "true"
+    ? await checkProjectLinks(username, repositoryName)
+    : await getProjectLinkChecks(username, repositoryName);
+
+  if (!checks) {
+    throw createApiError({
+      status: 404,
+      code: "not_found",
+      message: `the links of ${username}/${repositoryName} have not been checked`,
+    });
+  }
+
+  return checks;
+});
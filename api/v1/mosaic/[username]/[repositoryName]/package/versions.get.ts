@@ -0,0 +1,113 @@
+import type { PackageVersion, Registry } from "~/utils/registry";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "List package versions",
+    description: "Lists every published version of the project package on npm or crates.io.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The published versions",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["registry", "name", "versions"],
+              properties: {
+                registry: { type: "string", enum: ["npm", "crates"] },
+                name: { type: "string" },
+                lastPublishedAt: { type: "string", format: "date-time" },
+                daysSinceLastPublish: { type: "integer" },
+                versions: {
+                  type: "array",
+                  description: "The published versions, newest first.",
+                  items: {
+                    type: "object",
+                    required: ["version", "publishedAt"],
+                    properties: {
+                      version: { type: "string" },
+                      publishedAt: { type: "string", format: "date-time" },
+                    },
+                  },
+                },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const resolvedMosaicConfig = await requireResolvedConfig(username, repositoryName);
+
+    const config = resolvedMosaicConfig.content;
+
+    let registry: Registry | undefined;
+    let name: string | undefined;
+    let versions: PackageVersion[] | undefined;
+
+    if (config.npm?.enabled) {
+      registry = "npm";
+      name = config.npm.name || (await getPackage(username, repositoryName)).name;
+      versions = name ? await getNpmVersions(name) : undefined;
+    } else {
+      const manifest = await getCargoManifest(username, repositoryName);
+
+      if (manifest?.package && manifest.package.publish !== false) {
+        registry = "crates";
+        name = manifest.package.name;
+        versions = await getCrateVersions(name);
+      }
+    }
+
+    if (!registry || !name || !versions) {
+      throw createApiError({
+        status: 404,
+        code: "package_not_found",
+        message: "repository has no published package",
+      });
+    }
+
+    const lastPublishedAt = versions[0]?.publishedAt;
+
+    return {
+      registry,
+      name,
+      lastPublishedAt,
+      daysSinceLastPublish: lastPublishedAt
+        ? Math.floor((Date.now() - new Date(lastPublishedAt).getTime()) / (1000 * 60 * 60 * 24))
+        : undefined,
+      versions,
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
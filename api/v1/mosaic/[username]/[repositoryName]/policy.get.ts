@@ -0,0 +1,94 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get repository policy",
+    description: "Checks a project for a license, code of conduct, security policy and a protected default branch. The checks are also part of the onboarding checklist.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The policy report",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["repository", "checks", "diagnostics"],
+              properties: {
+                repository: { type: "string" },
+                checks: { type: "array", items: { $ref: "#/components/schemas/ChecklistItem" } },
+                diagnostics: { type: "array", items: { $ref: "#/components/schemas/Diagnostic" } },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+interface Diagnostic {
+  level: "warning" | "error";
+  message: string;
+}
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const resolvedMosaicConfig = await requireResolvedConfig(username, repositoryName);
+
+    if (resolvedMosaicConfig.content.project.ignore) {
+      throw createApiError({
+        status: 404,
+        code: "project_ignored",
+        message: "repository is ignored",
+      });
+    }
+
+    const report = await getRepositoryPolicy(username, repositoryName);
+
+    if (!report) {
+      throw createApiError({
+        status: 404,
+        code: "project_not_found",
+        message: "repository not found",
+      });
+    }
+
+    const diagnostics: Diagnostic[] = report.checks
+      .filter((check) => !check.done)
+      .map((check) => ({
+        level: "warning",
+        message: `${report.repository} does not ${check.label.replace(/^has/, "have").replace(/^protects/, "protect")}`,
+      }));
+
+    return {
+      repository: report.repository,
+      checks: report.checks,
+      diagnostics,
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
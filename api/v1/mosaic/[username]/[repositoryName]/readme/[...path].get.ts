@@ -4,6 +4,50 @@ import { COMMENT_REMOVER } from "~~/transformers/remove-comments";
 import { UNUSED_DEFINITION_REMOVER } from "~~/transformers/unused-definition-remover";
 import { URL_REWRITER } from "~~/transformers/url-rewriter";
 
+defineRouteMeta({
+  openAPI: {
+    tags: ["readme"],
+    summary: "Get README at path",
+    description: "Resolves the README at the given path, optionally transformed for `luxass.dev`.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+      {
+        in: "path",
+        name: "path",
+        required: true,
+        description: "The path to the directory of the README.",
+        schema: { type: "string" },
+      },
+      {
+        in: "query",
+        name: "transform",
+        description: "Transform the README for `luxass.dev`. Requires the `x-transform-name` header.",
+        schema: { type: "boolean" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The README",
+        content: {
+          "application/json": {
+            schema: { $ref: "#/components/schemas/Readme" },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
 export default defineLazyEventHandler(async () => {
   const remark = await import("remark").then((m) => m.remark);
   return defineCachedEventHandler(async (event) => {
@@ -11,8 +55,9 @@ export default defineLazyEventHandler(async () => {
     const repositoryName = getRouterParam(event, "repositoryName");
 
     if (!username || !repositoryName) {
-      throw createError({
+      throw createApiError({
         status: 400,
+        code: "missing_params",
         message: "missing username or repository name",
       });
     }
@@ -25,15 +70,17 @@ export default defineLazyEventHandler(async () => {
     });
 
     if (!readme || readme.type === "not_found") {
-      throw createError({
+      throw createApiError({
         status: 404,
+        code: "readme_not_found",
         message: "repository has no readme defined",
       });
     }
 
     if (readme.type === "error") {
-      throw createError({
+      throw createApiError({
         status: 500,
+        code: "readme_error",
         message: "error resolving readme",
         data: readme.details,
       });
@@ -47,8 +94,9 @@ export default defineLazyEventHandler(async () => {
       const projectName = getRequestHeader(event, "x-transform-name");
 
       if (!projectName?.trim()) {
-        throw createError({
+        throw createApiError({
           status: 400,
+          code: "missing_params",
           message: "missing project name",
         });
       }
@@ -1 +1,38 @@
 export { default } from "./[...path].get";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["readme"],
+    summary: "Get README",
+    description: "Resolves the README of a repository, optionally transformed for `luxass.dev`.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+      {
+        in: "query",
+        name: "transform",
+        description: "Transform the README for `luxass.dev`. Requires the `x-transform-name` header.",
+        schema: { type: "boolean" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The README",
+        content: {
+          "application/json": {
+            schema: { $ref: "#/components/schemas/Readme" },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
@@ -0,0 +1,106 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Report a problem",
+    description: "Reports a broken link, security issue or other problem with a project. Reports are stored in the admin inbox, and never published.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    requestBody: {
+      required: true,
+      content: {
+        "application/json": {
+          schema: {
+            type: "object",
+            required: ["kind", "message"],
+            properties: {
+              kind: { type: "string", enum: ["broken_link", "security", "other"] },
+              message: { type: "string", maxLength: 2000 },
+              url: { type: "string", format: "uri" },
+              contact: { type: "string", description: "How to reach you, only visible to the maintainer." },
+              captchaToken: { type: "string", description: "A Cloudflare Turnstile token, required when captchas are enabled." },
+            },
+          },
+        },
+      },
+    },
+    responses: {
+      "202": {
+        description: "The report was received",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["id"],
+              properties: {
+                id: { type: "string", description: "The id of the report." },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+      "429": {
+        $ref: "#/components/responses/TooManyRequests",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  const username = getRouterParam(event, "username");
+  const repositoryName = getRouterParam(event, "repositoryName");
+
+  if (!username || !repositoryName) {
+    throw createApiError({
+      status: 400,
+      code: "missing_params",
+      message: "missing params",
+    });
+  }
+
+  const result = REPORT_BODY_SCHEMA.safeParse(await readBody(event).catch(() => undefined));
+
+  if (!result.success) {
+    throw createApiError({
+      status: 400,
+      code: "invalid_request",
+      message: "invalid report",
+      data: result.error,
+    });
+  }
+
+  if (!await verifyCaptcha(result.data.captchaToken, getRequestIP(event))) {
+    throw createApiError({
+      status: 400,
+      code: "captcha_failed",
+      message: "captcha verification failed",
+    });
+  }
+
+  // only projects shown by mosaic can be reported, so the inbox can't be filled with arbitrary repositories.
+  const resolvedMosaicConfig = await resolveMosaicConfig(username, repositoryName);
+
+  if (resolvedMosaicConfig.type !== "resolved" || resolvedMosaicConfig.content.project.ignore) {
+    throw createApiError({
+      status: 404,
+      code: "project_not_found",
+      message: "project not found",
+    });
+  }
+
+  const report = await createReport(username, repositoryName, result.data);
+
+  setResponseStatus(event, 202);
+
+  return {
+    id: report.id,
+    status: report.status,
+  };
+});
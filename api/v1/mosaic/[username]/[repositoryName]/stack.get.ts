@@ -0,0 +1,61 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get project stack",
+    description: "Returns the direct dependencies of a project from its `package.json`, `Cargo.toml` and `pyproject.toml`, and the stacks detected from them.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The stack of the project",
+        content: {
+          "application/json": {
+            schema: { $ref: "#/components/schemas/ProjectStack" },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    // stacks are only written by the `stack:sync` task.
+    const stack = await getProjectStack(username, repositoryName);
+
+    if (!stack) {
+      throw createApiError({
+        status: 404,
+        code: "not_found",
+        message: `the stack of ${username}/${repositoryName} has not been synced`,
+      });
+    }
+
+    return stack;
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
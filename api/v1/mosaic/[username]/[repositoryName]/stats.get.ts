@@ -0,0 +1,115 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get project stats",
+    description: "Returns the daily snapshots of the stars, forks and open issues of a project, how much they changed within the range, and its page views.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+      {
+        in: "query",
+        name: "range",
+        description: "How far back to go, in days, weeks or months, e.g. `90d`, `12w` or `6m`. Defaults to `30d`.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The stats of the project",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["owner", "repository", "range", "delta", "snapshots", "views"],
+              properties: {
+                owner: { type: "string" },
+                repository: { type: "string" },
+                range: { type: "string" },
+                delta: { $ref: "#/components/schemas/StatsDelta" },
+                snapshots: { type: "array", items: { $ref: "#/components/schemas/StatsSnapshot" } },
+                views: {
+                  type: "object",
+                  required: ["total", "month", "daily"],
+                  properties: {
+                    total: { type: "integer", description: "The views within the range." },
+                    month: { type: "integer", description: "The views of the current month." },
+                    daily: {
+                      type: "array",
+                      items: {
+                        type: "object",
+                        required: ["date", "views"],
+                        properties: {
+                          date: { type: "string", format: "date" },
+                          views: { type: "integer" },
+                        },
+                      },
+                    },
+                  },
+                },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const { range = "30d" } = getQuery(event);
+    const days = typeof range === "string" ? parseStatsRange(range) : undefined;
+
+    if (!days) {
+      throw createApiError({
+        status: 400,
+        code: "invalid_request",
+        message: "invalid range, expected e.g. 30d, 12w or 6m",
+      });
+    }
+
+    const stats = await getProjectStats(username, repositoryName);
+
+    if (!stats) {
+      throw createApiError({
+        status: 404,
+        code: "not_found",
+        message: "no stats have been recorded for this project",
+      });
+    }
+
+    const snapshots = snapshotsInRange(stats.snapshots, days);
+
+    return {
+      owner: stats.owner,
+      repository: stats.repository,
+      range,
+      delta: computeStatsDelta(snapshots),
+      snapshots,
+      views: summarizeViews(await getProjectViews(username, repositoryName, viewsSince(days)), days),
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
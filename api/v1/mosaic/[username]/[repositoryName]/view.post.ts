@@ -0,0 +1,68 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Count a view",
+    description: "Counts a view of a project page. Views from bots and prefetches are ignored. The views are aggregated per day, and returned by the stats of the project.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "202": {
+        description: "The view was counted, or ignored",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["counted"],
+              properties: {
+                counted: { type: "boolean", description: "`false` when the view was made by a bot." },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+      "429": {
+        $ref: "#/components/responses/TooManyRequests",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(async (event) => {
+  const username = getRouterParam(event, "username");
+  const repositoryName = getRouterParam(event, "repositoryName");
+
+  if (!username || !repositoryName) {
+    throw createApiError({
+      status: 400,
+      code: "missing_params",
+      message: "missing params",
+    });
+  }
+
+  // views can't be counted for arbitrary repositories.
+  if (!await isViewableProject(username, repositoryName)) {
+    throw createApiError({
+      status: 404,
+      code: "project_not_found",
+      message: "project not found",
+    });
+  }
+
+  setResponseStatus(event, 202);
+
+  if (isBotView(event)) {
+    return { counted: false };
+  }
+
+  await recordProjectView(username, repositoryName);
+
+  return { counted: true };
+});
@@ -0,0 +1,64 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get config warnings",
+    description: "Lists the deprecated keys used in the `mosaic.toml` of a project, with their replacement and when they stop being supported.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+      { $ref: "#/components/parameters/repositoryName" },
+    ],
+    responses: {
+      "200": {
+        description: "The config warnings",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["path", "warnings"],
+              properties: {
+                path: { type: "string", description: "The url of the config." },
+                version: { type: "integer", description: "The version of the config format." },
+                warnings: { type: "array", items: { $ref: "#/components/schemas/ConfigWarning" } },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const username = getRouterParam(event, "username");
+    const repositoryName = getRouterParam(event, "repositoryName");
+
+    if (!username || !repositoryName) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const resolvedMosaicConfig = await requireResolvedConfig(username, repositoryName);
+
+    return {
+      path: resolvedMosaicConfig.path,
+      version: resolvedMosaicConfig.content.version,
+      warnings: resolvedMosaicConfig.warnings,
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
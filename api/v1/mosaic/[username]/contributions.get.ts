@@ -2,6 +2,44 @@ import { graphql } from "@octokit/graphql";
 import type { User } from "github-schema";
 import { PROFILE_CONTRIBUTIONS_QUERY } from "~/utils/graphql-queries";
 
+defineRouteMeta({
+  openAPI: {
+    tags: ["profile"],
+    summary: "List contributions",
+    description: "Lists the public repositories a user has contributed to.",
+    parameters: [
+      { $ref: "#/components/parameters/username" },
+    ],
+    responses: {
+      "200": {
+        description: "The contributed repositories",
+        content: {
+          "application/json": {
+            schema: {
+              type: "array",
+              items: {
+                type: "object",
+                required: ["name", "nameWithOwner"],
+                properties: {
+                  name: { type: "string" },
+                  nameWithOwner: { type: "string" },
+                  description: { type: "string", nullable: true },
+                },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
 export default defineLazyEventHandler(async () => {
   const runtimeConfig = useRuntimeConfig();
 
@@ -10,8 +48,9 @@ export default defineLazyEventHandler(async () => {
       const username = getRouterParam(event, "username");
 
       if (!username) {
-        throw createError({
+        throw createApiError({
           status: 400,
+          code: "missing_params",
           message: "missing params",
         });
       }
@@ -31,8 +70,9 @@ export default defineLazyEventHandler(async () => {
       return user?.contributions?.nodes || [];
     } catch (err) {
       console.error(err);
-      throw createError({
+      throw createApiError({
         status: 500,
+        code: "internal_error",
         message: "internal server error",
       });
     }
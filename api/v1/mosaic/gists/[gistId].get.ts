@@ -0,0 +1,135 @@
+import type { ResolvedProject } from "~/types";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get gist project",
+    description: "Resolves a project backed by a GitHub Gist.",
+    parameters: [
+      {
+        in: "path",
+        name: "gistId",
+        required: true,
+        description: "The id of the gist.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The resolved project",
+        content: {
+          "application/json": {
+            schema: {
+              allOf: [
+                { $ref: "#/components/schemas/ResolvedProject" },
+                {
+                  type: "object",
+                  properties: {
+                    source: { type: "string", enum: ["gist"] },
+                    url: { type: "string", format: "uri" },
+                    pushedAt: { type: "string", format: "date-time" },
+                    files: { type: "array", items: { type: "object" } },
+                  },
+                },
+              ],
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const gistId = getRouterParam(event, "gistId");
+
+    if (!gistId) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing params",
+      });
+    }
+
+    const resolvedMosaicConfig = await resolveGistConfig(gistId);
+
+    if (!resolvedMosaicConfig || resolvedMosaicConfig.type === "not_found") {
+      throw createApiError({
+        status: 404,
+        code: "config_not_found",
+        message: "gist has no config defined",
+      });
+    }
+
+    if (resolvedMosaicConfig.type === "error") {
+      throw createApiError({
+        status: 400,
+        code: "config_invalid",
+        message: resolvedMosaicConfig.message,
+        data: resolvedMosaicConfig.details,
+      });
+    }
+
+    const gist = await getGist(gistId);
+
+    if (!gist) {
+      throw createApiError({
+        status: 404,
+        code: "project_not_found",
+        message: "gist not found",
+      });
+    }
+
+    const config = resolvedMosaicConfig.content;
+
+    if (config.project.ignore) {
+      throw createApiError({
+        status: 404,
+        code: "project_ignored",
+        message: "gist is ignored",
+      });
+    }
+
+    const files = Object.values(gist.files).flatMap((file) => file ? [file] : []);
+    const readme = files.find((file) => /^readme\.mdx?$/i.test(file.filename))
+      || files.find((file) => /\.mdx?$/i.test(file.filename));
+
+    const project: ResolvedProject = {
+      name: config.project.name,
+      ignore: false,
+      priority: config.project.priority || 0,
+      deprecated: config.deprecated,
+      description: localize(config.project.description, getRequestLocales(event)) || gist.description || undefined,
+      // gists have no rendered README, so the raw file is linked instead.
+      readme: config.readme?.enabled ? readme?.raw_url : undefined,
+    };
+
+    return {
+      ...project,
+      source: "gist",
+      url: gist.html_url,
+      owner: gist.owner?.login,
+      pushedAt: gist.updated_at,
+      files: files.map((file) => ({
+        name: file.filename,
+        language: file.language,
+        size: file.size,
+        url: file.raw_url,
+      })),
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    ...LOCALIZED_CACHE_OPTIONS,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
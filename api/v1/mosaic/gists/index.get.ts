@@ -0,0 +1,49 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "List gist projects",
+    description: "Lists the ids of every gist that is configured as a project.",
+    responses: {
+      "200": {
+        description: "The gist ids",
+        content: {
+          "application/json": {
+            schema: {
+              type: "array",
+              items: {
+                type: "object",
+                required: ["id", "url"],
+                properties: {
+                  id: { type: "string" },
+                  url: { type: "string", format: "uri", description: "The url of the gist project." },
+                },
+              },
+            },
+          },
+        },
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  const runtimeConfig = useRuntimeConfig();
+
+  return defineCachedEventHandler(async () => {
+    const ids = await getConfiguredGists();
+
+    return ids.map((id) => ({
+      id,
+      url: `${runtimeConfig.siteUrl}/api/v1/mosaic/gists/${id}`,
+    }));
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
@@ -1,8 +1,180 @@
+import type { Page } from "~/utils/pagination";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "List projects",
+    description: "Lists every project showcased on `luxass.dev`. Send `Accept: text/csv` or `Accept: application/x-ndjson`, or use `?format=`, to export the projects. Pass `?limit=` or `?cursor=` to get a page of projects as JSON instead of every project.",
+    parameters: [
+      {
+        in: "query",
+        name: "format",
+        description: "The format of the response, overriding the `Accept` header.",
+        schema: { type: "string", enum: ["json", "csv", "ndjson"] },
+      },
+      {
+        in: "query",
+        name: "fields",
+        description: "Comma separated fields to export, nested fields use dots, e.g. `name,nameWithOwner,language.name`. Only used for CSV and NDJSON.",
+        schema: { type: "string" },
+      },
+      {
+        in: "query",
+        name: "license",
+        description: "Only list projects with this SPDX license id, e.g. `MIT`. Matched case-insensitively.",
+        schema: { type: "string" },
+      },
+      { $ref: "#/components/parameters/limit" },
+      { $ref: "#/components/parameters/cursor" },
+    ],
+    responses: {
+      "200": {
+        description: "The projects",
+        content: {
+          "application/json": {
+            schema: {
+              oneOf: [
+                {
+                  type: "array",
+                  items: { $ref: "#/components/schemas/Project" },
+                },
+                {
+                  allOf: [
+                    { $ref: "#/components/schemas/Page" },
+                    {
+                      type: "object",
+                      properties: {
+                        items: { type: "array", items: { $ref: "#/components/schemas/Project" } },
+                      },
+                    },
+                  ],
+                },
+              ],
+            },
+          },
+          "text/csv": {
+            schema: { type: "string" },
+          },
+          "application/x-ndjson": {
+            schema: { type: "string", description: "A project per line." },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
 export default defineLazyEventHandler(async () => {
   const runtimeConfig = useRuntimeConfig();
   return defineEventHandler(async (event) => {
-    // eslint-disable-next-line no-console
-    console.log("event", event);
-    return proxyRequest(event, `${runtimeConfig.worker}/repositories`);
+    const format = negotiateExportFormat(event);
+
+    if (!format) {
+      throw createApiError({
+        status: 400,
+        code: "invalid_request",
+        message: "unknown format, expected one of json, csv, ndjson",
+      });
+    }
+
+    const { fields, license, limit, cursor } = getQuery(event);
+    const page = format === "json" && (limit != null || cursor != null) ? getPageOptions(event) : undefined;
+
+    if (format === "json" && typeof license !== "string" && !page) {
+      return proxyRequest(event, `${runtimeConfig.worker}/repositories`);
+    }
+
+    const res = await fetch(`${runtimeConfig.worker}/repositories`);
+
+    if (!res.ok || !res.body) {
+      throw createApiError({
+        status: 502,
+        code: "upstream_error",
+        message: `failed to fetch projects, worker responded with ${res.status}`,
+      });
+    }
+
+    // stored licenses include overrides from `mosaic.toml`, so they win over the license the worker knows.
+    const licenses = typeof license === "string" ? await getStoredLicenses() : undefined;
+
+    async function* readProjects(body: ReadableStream<Uint8Array>): AsyncGenerator<Record<string, unknown>> {
+      for await (const project of parseJsonArrayStream(body)) {
+        if (!project || typeof project !== "object") {
+          continue;
+        }
+
+        if (licenses && typeof license === "string") {
+          const nameWithOwner = "nameWithOwner" in project && typeof project.nameWithOwner === "string" ? project.nameWithOwner : undefined;
+          const spdxId = (nameWithOwner && licenses.get(nameWithOwner.toLowerCase()))
+            || ("license" in project && typeof project.license === "string" ? project.license : undefined);
+
+          if (spdxId?.toLowerCase() !== license.toLowerCase()) {
+            continue;
+          }
+        }
+
+        yield project as Record<string, unknown>;
+      }
+    }
+
+    if (page) {
+      const items: Record<string, unknown>[] = [];
+      let afterCursor = page.cursor == null;
+      let nextCursor: string | null = null;
+
+      // the cursor is the `nameWithOwner` of the last project on the previous page.
+      for await (const project of readProjects(res.body)) {
+        if (!afterCursor) {
+          afterCursor = project.nameWithOwner === page.cursor;
+          continue;
+        }
+
+        if (items.length === page.limit) {
+          nextCursor = String(items.at(-1)!.nameWithOwner);
+          break;
+        }
+
+        items.push(project);
+      }
+
+      if (nextCursor) {
+        // the rest of the projects aren't needed, so the worker response isn't read to the end.
+        await res.body.cancel();
+      }
+
+      // the project of the cursor was removed, or the cursor was never valid.
+      if (!afterCursor) {
+        throw createApiError({
+          status: 400,
+          code: "invalid_request",
+          message: "unknown cursor, start again without `?cursor=`",
+        });
+      }
+
+      return { items, nextCursor } satisfies Page<Record<string, unknown>>;
+    }
+
+    if (format === "json") {
+      const projects: Record<string, unknown>[] = [];
+
+      for await (const project of readProjects(res.body)) {
+        projects.push(project);
+      }
+
+      return projects;
+    }
+
+    return sendExport(
+      event,
+      readProjects(res.body),
+      format,
+      typeof fields === "string" ? fields.split(",").map((field) => field.trim()).filter(Boolean) : undefined,
+    );
   });
 });
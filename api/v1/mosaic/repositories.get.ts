@@ -0,0 +1,50 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "List repositories",
+    description: "Lists the public repositories of every configured user and organization, filtered by their include and exclude patterns.",
+    parameters: [
+      {
+        in: "query",
+        name: "license",
+        description: "Only list repositories with this license, as an SPDX id, e.g. `MIT`.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The repositories",
+        content: {
+          "application/json": {
+            schema: {
+              type: "array",
+              items: { $ref: "#/components/schemas/Repository" },
+            },
+          },
+        },
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const { license } = getQuery(event);
+
+    const repositories = await getOwnerRepositories();
+    const licenses = await getRepositoryLicenses(repositories);
+
+    return repositories
+      .map((repository) => ({ ...repository, license: licenses.get(repository.nameWithOwner) ?? null }))
+      .filter((repository) => typeof license !== "string" || repository.license?.toLowerCase() === license.toLowerCase());
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
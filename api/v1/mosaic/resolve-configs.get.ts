@@ -1,5 +1,48 @@
 import { z } from "zod";
 
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Resolve configs",
+    description: "Resolves the configs of up to 10 repositories at once.",
+    parameters: [
+      {
+        in: "header",
+        name: "x-mosaic-repositories",
+        required: true,
+        description: "A comma separated list of `owner/name` repositories.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The resolved configs",
+        content: {
+          "application/json": {
+            schema: {
+              type: "array",
+              items: {
+                type: "object",
+                required: ["repository", "type"],
+                properties: {
+                  repository: { type: "string" },
+                  type: { type: "string", enum: ["success", "not_found", "error"] },
+                  content: { $ref: "#/components/schemas/MosaicConfig" },
+                  external: { type: "boolean" },
+                  details: { description: "Why the config is invalid, when `type` is `error`." },
+                },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+    },
+  },
+});
+
 const GITHUB_REPOSITORIES = z.array(z.string()).max(10);
 
 export default defineLazyEventHandler(async () => {
@@ -7,8 +50,9 @@ export default defineLazyEventHandler(async () => {
     const repositoriesRaw = getRequestHeader(event, "x-mosaic-repositories");
 
     if (!repositoriesRaw?.trim()) {
-      throw createError({
+      throw createApiError({
         status: 400,
+        code: "missing_params",
         message: "missing x-mosaic-repositories header",
       });
     }
@@ -16,8 +60,9 @@ export default defineLazyEventHandler(async () => {
     const splittedRepositories = repositoriesRaw.trim().split(",");
 
     if (splittedRepositories.length > 10) {
-      throw createError({
+      throw createApiError({
         status: 400,
+        code: "invalid_request",
         message: "too many repositories in single request",
       });
     }
@@ -25,8 +70,9 @@ export default defineLazyEventHandler(async () => {
     const result = GITHUB_REPOSITORIES.safeParse(splittedRepositories);
     if (!result.success) {
       console.error("invalid repositories", result.error);
-      throw createError({
+      throw createApiError({
         status: 400,
+        code: "invalid_request",
         message: "invalid repositories",
         data: result.error,
       });
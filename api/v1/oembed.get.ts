@@ -0,0 +1,188 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "oEmbed",
+    description: "An oEmbed provider for project cards. Accepts GitHub repository urls of the configured owners and mosaic project urls.",
+    parameters: [
+      {
+        in: "query",
+        name: "url",
+        required: true,
+        description: "The url of the project, e.g. `https://github.com/luxass/mosaic`.",
+        schema: { type: "string" },
+      },
+      {
+        in: "query",
+        name: "maxwidth",
+        description: "The maximum width of the embed.",
+        schema: { type: "integer" },
+      },
+      {
+        in: "query",
+        name: "format",
+        description: "Only `json` is supported.",
+        schema: { type: "string", enum: ["json"] },
+      },
+      { $ref: "#/components/parameters/lang" },
+    ],
+    responses: {
+      "200": {
+        description: "The oEmbed response",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              description: "An oEmbed `rich` response.",
+              required: ["version", "type", "title", "html", "width", "height"],
+              properties: {
+                version: { type: "string", enum: ["1.0"] },
+                type: { type: "string", enum: ["rich"] },
+                title: { type: "string" },
+                author_name: { type: "string" },
+                author_url: { type: "string", format: "uri" },
+                provider_name: { type: "string" },
+                provider_url: { type: "string", format: "uri" },
+                cache_age: { type: "integer" },
+                thumbnail_url: { type: "string", format: "uri" },
+                thumbnail_width: { type: "integer" },
+                thumbnail_height: { type: "integer" },
+                html: { type: "string" },
+                width: { type: "integer" },
+                height: { type: "integer" },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+      "501": {
+        $ref: "#/components/responses/NotImplemented",
+      },
+    },
+  },
+});
+
+const DEFAULT_WIDTH = 400;
+const HEIGHT = 160;
+
+function escapeHtml(value: string): string {
+  return value
+    .replace(/&/g, "&amp;")
+    .replace(/</g, "&lt;")
+    .replace(/>/g, "&gt;")
+    .replace(/"/g, "&quot;");
+}
+
+// accepts `https://github.com/<owner>/<name>` of a configured owner and `<siteUrl>/api/v1/mosaic/<owner>/<name>`,
+// so the endpoint can't be used to render cards of arbitrary repositories.
+function parseProjectUrl(url: string, siteUrl: string): { owner: string; repository: string } | undefined {
+  let parsed: URL;
+  try {
+    parsed = new URL(url);
+  } catch {
+    return undefined;
+  }
+
+  const segments = parsed.pathname.split("/").filter(Boolean);
+
+  if (parsed.hostname === "github.com") {
+    return segments.length >= 2 && isConfiguredOwner(segments[0])
+      ? { owner: segments[0], repository: segments[1] }
+      : undefined;
+  }
+
+  if (parsed.origin !== new URL(siteUrl).origin) {
+    return undefined;
+  }
+
+  if (segments.length >= 5 && segments.slice(0, 3).join("/") === "api/v1/mosaic") {
+    return { owner: segments[3], repository: segments[4] };
+  }
+
+  return undefined;
+}
+
+export default defineLazyEventHandler(async () => {
+  const runtimeConfig = useRuntimeConfig();
+
+  return defineCachedEventHandler(async (event) => {
+    const query = getQuery(event);
+
+    if (typeof query.url !== "string" || !query.url) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing url",
+      });
+    }
+
+    if (query.format && query.format !== "json") {
+      throw createApiError({
+        status: 501,
+        code: "not_implemented",
+        message: "only the json format is supported",
+      });
+    }
+
+    const project = parseProjectUrl(query.url, runtimeConfig.siteUrl);
+
+    if (!project) {
+      throw createApiError({
+        status: 404,
+        code: "project_not_found",
+        message: "url does not point to a project",
+      });
+    }
+
+    const card = await getProjectCard(project.owner, project.repository, getRequestLocales(event));
+
+    if (!card) {
+      throw createApiError({
+        status: 404,
+        code: "project_not_found",
+        message: "repository not found",
+      });
+    }
+
+    const maxWidth = Number(query.maxwidth);
+    const width = Number.isFinite(maxWidth) && maxWidth > 0 ? Math.min(maxWidth, DEFAULT_WIDTH) : DEFAULT_WIDTH;
+
+    const language = card.languages[0];
+
+    const html = `<a href="${escapeHtml(card.website || card.url)}" style="display:block;box-sizing:border-box;width:${width}px;height:${HEIGHT}px;padding:16px;border:1px solid #e4e4e7;border-radius:8px;font-family:sans-serif;color:inherit;text-decoration:none">`
+      + `<strong>${escapeHtml(card.name)}</strong>`
+      + `${card.description ? `<p style="margin:8px 0">${escapeHtml(card.description)}</p>` : ""}`
+      + `<span>★ ${card.stars}</span>`
+      + `${language ? ` <span style="color:${escapeHtml(language.color)}">●</span> ${escapeHtml(language.name)}` : ""}`
+      + `</a>`;
+
+    return {
+      version: "1.0",
+      type: "rich",
+      title: card.name,
+      author_name: project.owner,
+      author_url: `https://github.com/${project.owner}`,
+      provider_name: "mosaic",
+      provider_url: runtimeConfig.siteUrl,
+      cache_age: 60 * 60,
+      thumbnail_url: card.ogImage,
+      thumbnail_width: 1280,
+      thumbnail_height: 640,
+      html,
+      width,
+      height: HEIGHT,
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    ...LOCALIZED_CACHE_OPTIONS,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
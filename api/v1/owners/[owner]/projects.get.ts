@@ -0,0 +1,117 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "List owner projects",
+    description: "Lists the projects of a single tracked user or organization, ordered by priority.",
+    parameters: [
+      {
+        in: "path",
+        name: "owner",
+        required: true,
+        description: "The login of the user or organization.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The projects of the owner",
+        content: {
+          "application/json": {
+            schema: {
+              allOf: [
+                { $ref: "#/components/schemas/Owner" },
+                {
+                  type: "object",
+                  required: ["projects", "skipped"],
+                  properties: {
+                    projects: {
+                      type: "array",
+                      items: {
+                        type: "object",
+                        required: ["name", "handle", "priority", "nameWithOwner", "url"],
+                        properties: {
+                          name: { type: "string" },
+                          handle: { type: "string" },
+                          description: { type: "string" },
+                          priority: { type: "number" },
+                          nameWithOwner: { type: "string" },
+                          url: { type: "string", format: "uri" },
+                          stars: { type: "integer" },
+                          pushedAt: { type: "string", format: "date-time", nullable: true },
+                          license: { type: "string" },
+                        },
+                      },
+                    },
+                    skipped: {
+                      type: "array",
+                      description: "The repositories GitHub refused, e.g. when rate limited.",
+                      items: { type: "string" },
+                    },
+                  },
+                },
+              ],
+            },
+          },
+        },
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const login = getRouterParam(event, "owner");
+    const owner = login ? getOwner(login) : undefined;
+
+    if (!owner) {
+      throw createApiError({
+        status: 404,
+        code: "not_found",
+        message: "owner is not tracked by this instance",
+      });
+    }
+
+    const locales = getRequestLocales(event);
+    const repositories = (await getOwnerRepositories([owner])).filter((repository) => !repository.isArchived);
+
+    const { results: projects, skipped } = await settleRepositories(repositories, 10, async (repository) => {
+      const resolvedMosaicConfig = await resolveMosaicConfig(repository.owner, repository.name);
+
+      if (resolvedMosaicConfig.type !== "resolved" || resolvedMosaicConfig.content.project.ignore) {
+        return undefined;
+      }
+
+      const { project } = resolvedMosaicConfig.content;
+
+      return {
+        name: project.name,
+        handle: project.handle || repository.name,
+        description: localize(project.description, locales) || repository.description || undefined,
+        priority: project.priority,
+        nameWithOwner: repository.nameWithOwner,
+        url: repository.url,
+        stars: project.stars ? repository.stars : undefined,
+        pushedAt: repository.pushedAt,
+        license: project.license || repository.license || undefined,
+      };
+    });
+
+    return {
+      owner: owner.login,
+      type: owner.type,
+      siteUrl: owner.siteUrl,
+      projects: projects.sort((a, b) => b.priority - a.priority),
+      skipped: skipped.map((repository) => repository.nameWithOwner),
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    ...LOCALIZED_CACHE_OPTIONS,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
@@ -0,0 +1,29 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "List owners",
+    description: "Lists the users and organizations whose projects are tracked by this instance.",
+    responses: {
+      "200": {
+        description: "The owners",
+        content: {
+          "application/json": {
+            schema: {
+              type: "array",
+              items: { $ref: "#/components/schemas/Owner" },
+            },
+          },
+        },
+      },
+    },
+  },
+});
+
+export default defineEventHandler(() => {
+  // tokens are never exposed.
+  return getOwners().map((owner) => ({
+    login: owner.login,
+    type: owner.type,
+    siteUrl: owner.siteUrl,
+  }));
+});
@@ -0,0 +1,108 @@
+import { graphql } from "@octokit/graphql";
+import type { User } from "github-schema";
+import { PROFILE_VIEWER_QUERY } from "~/utils/graphql-queries";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["profile"],
+    summary: "Get profile",
+    description: "Returns the GitHub profile of the configured user.",
+    responses: {
+      "200": {
+        description: "The profile",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["login", "url", "avatarUrl", "followers", "following", "pinned", "socialAccounts"],
+              properties: {
+                login: { type: "string" },
+                name: { type: "string", nullable: true },
+                bio: { type: "string", nullable: true },
+                avatarUrl: { type: "string", format: "uri" },
+                url: { type: "string", format: "uri" },
+                websiteUrl: { type: "string", nullable: true },
+                location: { type: "string", nullable: true },
+                company: { type: "string", nullable: true },
+                twitterUsername: { type: "string", nullable: true },
+                followers: { type: "integer" },
+                following: { type: "integer" },
+                pinned: { type: "array", description: "The pinned repositories.", items: { type: "object" } },
+                socialAccounts: {
+                  type: "array",
+                  items: {
+                    type: "object",
+                    properties: {
+                      provider: { type: "string" },
+                      displayName: { type: "string" },
+                      url: { type: "string", format: "uri" },
+                    },
+                  },
+                },
+                sponsorsListing: {
+                  type: "object",
+                  nullable: true,
+                  properties: {
+                    name: { type: "string" },
+                    url: { type: "string", format: "uri" },
+                  },
+                },
+              },
+            },
+          },
+        },
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  const runtimeConfig = useRuntimeConfig();
+
+  return defineCachedEventHandler(async () => {
+    try {
+      const { viewer } = await graphql<{
+        viewer: User;
+      }>(PROFILE_VIEWER_QUERY, {
+        headers: {
+          "Authorization": `Bearer ${runtimeConfig.github.token}`,
+          "Content-Type": "application/json",
+        },
+      });
+
+      return {
+        login: viewer.login,
+        name: viewer.name,
+        bio: viewer.bio,
+        avatarUrl: viewer.avatarUrl,
+        url: viewer.url,
+        websiteUrl: viewer.websiteUrl,
+        location: viewer.location,
+        company: viewer.company,
+        twitterUsername: viewer.twitterUsername,
+        status: viewer.status,
+        followers: viewer.followers.totalCount,
+        following: viewer.following.totalCount,
+        pinned: viewer.pinnedItems.nodes || [],
+        socialAccounts: viewer.socialAccounts.nodes || [],
+        sponsorsListing: viewer.sponsorsListing,
+      };
+    } catch (err) {
+      console.error(err);
+      throw createApiError({
+        status: 500,
+        code: "internal_error",
+        message: "internal server error",
+      });
+    }
+  }, {
+    maxAge: 60 * 5, // 5 minutes
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
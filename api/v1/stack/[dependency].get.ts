@@ -0,0 +1,102 @@
+import type { Dependency, Ecosystem } from "~/utils/stack";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Find projects by dependency",
+    description: "Lists the projects that directly depend on a package, with the version they require.",
+    parameters: [
+      {
+        in: "path",
+        name: "dependency",
+        required: true,
+        description: "The name of the package, e.g. `zod` or `axum`. Scoped npm packages are url encoded, e.g. `%40types%2Fvscode`.",
+        schema: { type: "string" },
+      },
+      {
+        in: "query",
+        name: "ecosystem",
+        description: "Only match packages of this ecosystem.",
+        schema: { type: "string", enum: ["npm", "cargo", "pypi"] },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The projects using the dependency",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["dependency", "projects"],
+              properties: {
+                dependency: { type: "string" },
+                projects: {
+                  type: "array",
+                  items: {
+                    type: "object",
+                    required: ["nameWithOwner", "ecosystem"],
+                    properties: {
+                      nameWithOwner: { type: "string" },
+                      ecosystem: { type: "string", enum: ["npm", "cargo", "pypi"] },
+                      version: { type: "string", description: "The version requirement of the project." },
+                    },
+                  },
+                },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+    },
+  },
+});
+
+const ECOSYSTEMS: Ecosystem[] = ["npm", "cargo", "pypi"];
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const dependency = decodeURIComponent(getRouterParam(event, "dependency") || "");
+    const { ecosystem } = getQuery(event);
+
+    if (!dependency) {
+      throw createApiError({
+        status: 400,
+        code: "missing_params",
+        message: "missing dependency",
+      });
+    }
+
+    if (ecosystem != null && !ECOSYSTEMS.includes(ecosystem as Ecosystem)) {
+      throw createApiError({
+        status: 400,
+        code: "invalid_request",
+        message: `unknown ecosystem, expected one of ${ECOSYSTEMS.join(", ")}`,
+      });
+    }
+
+    const projects: ({ nameWithOwner: string } & Pick<Dependency, "ecosystem" | "version">)[] = [];
+
+    for await (const stack of iterateProjectStacks()) {
+      const match = stack.dependencies.find((item) => (!ecosystem || item.ecosystem === ecosystem)
+        && (item.ecosystem === "pypi" ? item.name === normalizePythonName(dependency) : item.name === dependency));
+
+      if (match) {
+        projects.push({ nameWithOwner: `${stack.owner}/${stack.repository}`, ecosystem: match.ecosystem, version: match.version });
+      }
+    }
+
+    return {
+      dependency,
+      projects,
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
@@ -0,0 +1,89 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "List stacks",
+    description: "Groups the projects by the stacks they are built with, as detected from their dependency manifests by the last `stack:sync`.",
+    responses: {
+      "200": {
+        description: "The projects grouped by stack",
+        content: {
+          "application/json": {
+            schema: {
+              type: "array",
+              description: "The stacks, most used first.",
+              items: {
+                allOf: [
+                  { $ref: "#/components/schemas/Stack" },
+                  {
+                    type: "object",
+                    required: ["projects"],
+                    properties: {
+                      projects: {
+                        type: "array",
+                        items: {
+                          type: "object",
+                          required: ["name", "owner", "nameWithOwner", "url"],
+                          properties: {
+                            name: { type: "string" },
+                            owner: { type: "string" },
+                            nameWithOwner: { type: "string" },
+                            url: { type: "string", format: "uri" },
+                          },
+                        },
+                      },
+                    },
+                  },
+                ],
+              },
+            },
+          },
+        },
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async () => {
+    const repositories = (await getOwnerRepositories()).filter((repository) => !repository.isArchived);
+
+    // the stacks are stored by `stack:sync`, which skips ignored projects and projects without a config.
+    const projects = await mapWithConcurrency(repositories, 10, async (repository) => {
+      const [owner, name] = repository.nameWithOwner.split("/");
+      const stack = await getProjectStack(owner, name);
+
+      return stack && {
+        name: stack.name || name,
+        owner: repository.owner,
+        nameWithOwner: repository.nameWithOwner,
+        url: repository.url,
+        stacks: stack.stacks,
+      };
+    });
+
+    const stacks = new Map<string, { id: string; name: string; projects: { name: string; owner: string; nameWithOwner: string; url: string }[] }>();
+
+    for (const project of projects) {
+      if (!project) {
+        continue;
+      }
+
+      for (const stack of project.stacks) {
+        const group = stacks.get(stack.id) || { ...stack, projects: [] };
+        group.projects.push({ name: project.name, owner: project.owner, nameWithOwner: project.nameWithOwner, url: project.url });
+        stacks.set(stack.id, group);
+      }
+    }
+
+    return [...stacks.values()].sort((a, b) => b.projects.length - a.projects.length);
+  }, {
+    maxAge: 60 * 60 * 6, // 6 hours
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
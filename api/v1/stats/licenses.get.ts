@@ -0,0 +1,65 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get license stats",
+    description: "Counts the repositories per license, including licenses overridden in `mosaic.toml`.",
+    responses: {
+      "200": {
+        description: "The repositories per license, most used first",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["total", "licenses"],
+              properties: {
+                total: { type: "integer", description: "The number of repositories." },
+                licenses: {
+                  type: "array",
+                  description: "The licenses, most used first.",
+                  items: {
+                    type: "object",
+                    required: ["license", "count", "repositories"],
+                    properties: {
+                      license: { type: "string", description: "The SPDX id of the license, `none` for repositories without one." },
+                      count: { type: "integer" },
+                      repositories: { type: "array", items: { type: "string" } },
+                    },
+                  },
+                },
+              },
+            },
+          },
+        },
+      },
+      "500": {
+        $ref: "#/components/responses/InternalServerError",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async () => {
+    const repositories = await getOwnerRepositories();
+    const licenses = await getRepositoryLicenses(repositories);
+
+    const counts = new Map<string, string[]>();
+    for (const [nameWithOwner, license] of licenses) {
+      const key = license ?? "none";
+      counts.set(key, [...counts.get(key) ?? [], nameWithOwner]);
+    }
+
+    return {
+      total: repositories.length,
+      licenses: [...counts.entries()]
+        .map(([license, repositories]) => ({ license, count: repositories.length, repositories }))
+        .sort((a, b) => b.count - a.count),
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
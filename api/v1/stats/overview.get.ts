@@ -0,0 +1,111 @@
+import type { StatsDelta, StatsSnapshot } from "~/utils/stats";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["projects"],
+    summary: "Get stats overview",
+    description: "Returns the combined stars, forks and open issues of every project, their change within the range, and the fastest growing projects.",
+    parameters: [
+      {
+        in: "query",
+        name: "range",
+        description: "How far back to go, in days, weeks or months, e.g. `90d`, `12w` or `6m`. Defaults to `30d`.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The stats overview",
+        content: {
+          "application/json": {
+            schema: {
+              type: "object",
+              required: ["range", "projects", "totals", "delta", "trending"],
+              properties: {
+                range: { type: "string" },
+                projects: { type: "integer", description: "The number of projects with snapshots in the range." },
+                totals: { $ref: "#/components/schemas/StatsDelta" },
+                delta: { $ref: "#/components/schemas/StatsDelta" },
+                trending: {
+                  type: "array",
+                  description: "The projects that gained the most stars in the range.",
+                  items: {
+                    type: "object",
+                    required: ["nameWithOwner", "stars"],
+                    properties: {
+                      nameWithOwner: { type: "string" },
+                      stars: { type: "integer" },
+                    },
+                  },
+                },
+              },
+            },
+          },
+        },
+      },
+      "400": {
+        $ref: "#/components/responses/BadRequest",
+      },
+    },
+  },
+});
+
+export default defineLazyEventHandler(async () => {
+  return defineCachedEventHandler(async (event) => {
+    const { range = "30d" } = getQuery(event);
+    const days = typeof range === "string" ? parseStatsRange(range) : undefined;
+
+    if (!days) {
+      throw createApiError({
+        status: 400,
+        code: "invalid_request",
+        message: "invalid range, expected e.g. 30d, 12w or 6m",
+      });
+    }
+
+    const projects: { nameWithOwner: string; current: StatsSnapshot; delta: StatsDelta }[] = [];
+
+    for await (const stats of iterateProjectStats()) {
+      const snapshots = snapshotsInRange(stats.snapshots, days);
+      const current = snapshots.at(-1);
+
+      if (current) {
+        projects.push({
+          nameWithOwner: `${stats.owner}/${stats.repository}`,
+          current,
+          delta: computeStatsDelta(snapshots),
+        });
+      }
+    }
+
+    const totals = { stars: 0, forks: 0, openIssues: 0 };
+    const delta = { stars: 0, forks: 0, openIssues: 0 };
+
+    for (const project of projects) {
+      totals.stars += project.current.stars;
+      totals.forks += project.current.forks;
+      totals.openIssues += project.current.openIssues;
+      delta.stars += project.delta.stars;
+      delta.forks += project.delta.forks;
+      delta.openIssues += project.delta.openIssues;
+    }
+
+    return {
+      range,
+      projects: projects.length,
+      totals,
+      delta,
+      trending: projects
+        .filter((project) => project.delta.stars > 0)
+        .sort((a, b) => b.delta.stars - a.delta.stars)
+        .slice(0, 10)
+        .map((project) => ({ nameWithOwner: project.nameWithOwner, stars: project.delta.stars })),
+    };
+  }, {
+    maxAge: 60 * 60, // 1 hour
+    swr: true,
+    shouldBypassCache() {
+      return import.meta.dev || false;
+    },
+  });
+});
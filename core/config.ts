@@ -0,0 +1,81 @@
+// this module only depends on `smol-toml` and `zod`, and has no access to the nitro runtime,
+// so it can be imported by the website to validate `mosaic.toml` in the browser.
+import { parse as parseToml } from "smol-toml";
+import type { z } from "zod";
+import { zodErrorMap } from "zod-error-utils";
+import { MOSAIC_SCHEMA } from "../utils/json-schema";
+import type { ConfigWarning } from "./migrations";
+import { migrateMosaicConfig } from "./migrations";
+
+export type MosaicConfig = z.infer<typeof MOSAIC_SCHEMA>;
+
+export type ParseConfigResult =
+  | { success: true; data: MosaicConfig; warnings: ConfigWarning[] }
+  | { success: false; message: string; details?: unknown };
+
+/**
+ * Parses and validates the content of a `mosaic.toml` file.
+ *
+ * @param {string} content - The raw TOML content.
+ * @returns {Promise<ParseConfigResult>} A promise that resolves to the validated config, or the reason it is not valid.
+ */
+export async function parseMosaicConfig(content: string): Promise<ParseConfigResult> {
+  let raw: unknown;
+  try {
+    raw = parseToml(content);
+  } catch (err) {
+    return {
+      success: false,
+      message: "error resolving config due to config not being valid toml",
+      details: err instanceof Error ? err.message : err,
+    };
+  }
+
+  const migrated = migrateMosaicConfig(raw);
+
+  if (!migrated.success) {
+    return {
+      success: false,
+      message: migrated.message,
+    };
+  }
+
+  const parsed = await MOSAIC_SCHEMA.safeParseAsync(migrated.config, {
+    errorMap: zodErrorMap,
+  });
+
+  if (!parsed.success) {
+    return {
+      success: false,
+      message: "error resolving config due to config not being valid",
+      details: parsed.error,
+    };
+  }
+
+  return {
+    success: true,
+    data: applyWorkspaceOverrides(parsed.data),
+    warnings: migrated.warnings,
+  };
+}
+
+/**
+ * Sets the project name of every workspace override to the key it is defined under.
+ *
+ * @param {MosaicConfig} config - The validated config.
+ * @returns {MosaicConfig} The same config, with the override names applied.
+ */
+export function applyWorkspaceOverrides(config: MosaicConfig): MosaicConfig {
+  if (config.workspace?.enabled && config.workspace.overrides != null) {
+    for (const [key] of Object.entries(config.workspace.overrides)) {
+      const projectOverride = config.workspace.overrides[key];
+      if (projectOverride == null) {
+        throw new Error("project not found, how did this happen?");
+      }
+
+      projectOverride.project.name = key;
+    }
+  }
+
+  return config;
+}
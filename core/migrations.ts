@@ -0,0 +1,115 @@
+// like `core/config.ts`, this module has no access to the nitro runtime.
+
+export interface ConfigWarning {
+  /**
+   * The dotted path of the deprecated key.
+   */
+  key: string;
+  /**
+   * The key to use instead, `undefined` when the key was removed without a replacement.
+   */
+  replacement?: string;
+  message: string;
+  /**
+   * The date after which the deprecated key is no longer migrated, as `YYYY-MM-DD`.
+   */
+  removeAfter: string;
+}
+
+export interface ConfigMigration {
+  from: number;
+  to: number;
+  migrate: (config: Record<string, any>, warn: (warning: ConfigWarning) => void) => void;
+}
+
+export type MigrateConfigResult =
+  | { success: true; config: Record<string, any>; version: number; warnings: ConfigWarning[] }
+  | { success: false; message: string };
+
+/**
+ * The migrations between config versions, in order. Every migration upgrades a config by one version,
+ * and renamed or removed keys should be reported through `warn` until their `removeAfter` date.
+ */
+const CONFIG_MIGRATIONS: ConfigMigration[] = [];
+
+function getCurrentVersion(migrations: ConfigMigration[]): number {
+  return migrations.at(-1)?.to ?? 1;
+}
+
+/**
+ * The version of the `mosaic.toml` format, configs without a `version` are treated as version 1.
+ */
+export const CURRENT_CONFIG_VERSION = getCurrentVersion(CONFIG_MIGRATIONS);
+
+/**
+ * Renames a deprecated key of a raw config, warning about its use. The new key wins, when both are set.
+ *
+ * @param {Record<string, any> | undefined} target - The object containing the key, e.g. `config.project`.
+ * @param {string} from - The deprecated key.
+ * @param {string} to - The key to use instead.
+ * @param {string} path - The path of the object, used in the warning.
+ * @param {string} removeAfter - The date after which the deprecated key is no longer migrated, as `YYYY-MM-DD`.
+ * @param {(warning: ConfigWarning) => void} warn - Collects the warning.
+ */
+export function renameKey(
+  target: Record<string, any> | undefined,
+  from: string,
+  to: string,
+  path: string,
+  removeAfter: string,
+  warn: (warning: ConfigWarning) => void,
+) {
+  if (target == null || typeof target !== "object" || !(from in target)) {
+    return;
+  }
+
+  if (!(to in target)) {
+    target[to] = target[from];
+  }
+
+  delete target[from];
+
+  warn({
+    key: `${path}.${from}`,
+    replacement: `${path}.${to}`,
+    message: `\`${path}.${from}\` is deprecated, use \`${path}.${to}\` instead.`,
+    removeAfter,
+  });
+}
+
+/**
+ * Upgrades a raw config to the current version, before it is validated.
+ *
+ * @param {unknown} raw - The parsed, but not yet validated, TOML.
+ * @param {ConfigMigration[]} [migrations] - The migrations to apply, defaults to the migrations of the current format.
+ * @returns {MigrateConfigResult} The upgraded config and the deprecations it used, or why it could not be upgraded.
+ */
+export function migrateMosaicConfig(raw: unknown, migrations: ConfigMigration[] = CONFIG_MIGRATIONS): MigrateConfigResult {
+  const currentVersion = getCurrentVersion(migrations);
+
+  if (raw == null || typeof raw !== "object" || Array.isArray(raw)) {
+    // leave it to the schema to report what is wrong.
+    return { success: true, config: raw as Record<string, any>, version: currentVersion, warnings: [] };
+  }
+
+  const config = structuredClone(raw) as Record<string, any>;
+  const version = config.version ?? 1;
+
+  if (!Number.isInteger(version) || version < 1 || version > currentVersion) {
+    return {
+      success: false,
+      message: `error resolving config due to unsupported version ${version}, expected 1 to ${currentVersion}`,
+    };
+  }
+
+  const warnings: ConfigWarning[] = [];
+  for (const migration of migrations) {
+    if (migration.from >= version) {
+      migration.migrate(config, (warning) => warnings.push(warning));
+    }
+  }
+
+  config.version = currentVersion;
+
+  return { success: true, config, version, warnings };
+}
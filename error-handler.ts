@@ -1,10 +1,71 @@
+import type { ApiErrorData } from "~/utils/errors";
+import { GitHubError } from "~/utils/github";
+
+interface FieldError {
+  field: string;
+  message: string;
+}
+
+// zod errors are passed as details, when a config or request is not valid.
+function toFieldErrors(details: unknown): FieldError[] | undefined {
+  if (!details || typeof details !== "object" || !("issues" in details) || !Array.isArray(details.issues)) {
+    return undefined;
+  }
+
+  return details.issues.map((issue: { path: (string | number)[]; message: string }) => ({
+    field: issue.path.join("."),
+    message: issue.message,
+  }));
+}
+
 export default defineNitroErrorHandler((error, event) => {
-  setResponseHeader(event, "Content-Type", "application/json");
+  // unhandled github errors are wrapped by h3, with the original error as the cause.
+  const githubError = error.cause instanceof GitHubError ? error.cause : undefined;
+  const githubStatus = githubError ? getGitHubErrorStatus(githubError) : undefined;
+
+  const status = githubStatus?.status || error.statusCode || 500;
+  const data = (githubError
+    ? { code: githubStatus!.code, details: githubError.errors }
+    : error.data) as Partial<ApiErrorData> | undefined;
+  const code = data?.code || defaultErrorCode(status);
+
+  setResponseStatus(event, status);
+  setResponseHeader(event, "Content-Type", "application/problem+json");
+
+  if (githubError?.retryAfter != null) {
+    setResponseHeader(event, "Retry-After", String(githubError.retryAfter));
+  }
 
   return send(event, JSON.stringify({
-    path: event.path,
+    type: `https://mosaic.luxass.dev/problems/${code}`,
+    title: getStatusText(status),
+    status,
+    detail: error.message,
+    instance: event.path,
+    code,
+    trace_id: event.context.traceId || crypto.randomUUID(),
     timestamp: new Date().toISOString(),
-    message: error.message,
-    status: error.statusCode || 500,
+    errors: toFieldErrors(data?.details),
   }));
 });
+
+function getStatusText(status: number): string {
+  switch (status) {
+    case 400:
+      return "Bad Request";
+    case 401:
+      return "Unauthorized";
+    case 403:
+      return "Forbidden";
+    case 404:
+      return "Not Found";
+    case 429:
+      return "Too Many Requests";
+    case 502:
+      return "Bad Gateway";
+    case 503:
+      return "Service Unavailable";
+    default:
+      return status >= 500 ? "Internal Server Error" : "Error";
+  }
+}
@@ -0,0 +1,31 @@
+import { minimatch } from "minimatch";
+import type { CorsGroup } from "~/utils/cors";
+
+export default defineEventHandler((event) => {
+  const { cors } = useRuntimeConfig();
+
+  const path = event.path.split("?")[0];
+  const group = (cors.groups as CorsGroup[]).find((group) => minimatch(path, group.pattern));
+
+  if (!group) {
+    return;
+  }
+
+  const origins = group.origins || cors.origins;
+
+  const handled = handleCors(event, {
+    origin: (origin) => isAllowedOrigin(origin, origins),
+    methods: group.methods,
+    allowHeaders: ["authorization", "content-type", "x-transform", "x-transform-name", "x-mosaic-repositories", "x-request-id", "traceparent"],
+    exposeHeaders: ["x-request-id", "ratelimit-limit", "ratelimit-remaining", "ratelimit-reset", "retry-after"],
+    maxAge: String(cors.maxAge),
+    preflight: {
+      statusCode: 204,
+    },
+  });
+
+  // preflight requests are answered by `handleCors`
+  if (handled) {
+    return null;
+  }
+});
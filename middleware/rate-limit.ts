@@ -0,0 +1,43 @@
+import { minimatch } from "minimatch";
+import type { RateLimitGroup } from "~/utils/rate-limit";
+
+const limiter = new TokenBucketLimiter();
+
+export default defineEventHandler((event) => {
+  const { rateLimit } = useRuntimeConfig();
+
+  if (!rateLimit.enabled) {
+    return;
+  }
+
+  const path = event.path.split("?")[0];
+  const group = (rateLimit.groups as RateLimitGroup[]).find((group) => minimatch(path, group.pattern));
+
+  if (!group) {
+    return;
+  }
+
+  // forwarding headers are only trusted when configured, since any client can send them.
+  // without one, the socket address is used.
+  const ip = (rateLimit.ipHeader && getRequestHeader(event, rateLimit.ipHeader)?.split(",")[0]?.trim())
+    || getRequestIP(event)
+    || "unknown";
+
+  const result = limiter.take(`${group.name}:${ip}`, group);
+
+  setResponseHeaders(event, {
+    "RateLimit-Limit": String(result.limit),
+    "RateLimit-Remaining": String(result.remaining),
+    "RateLimit-Reset": String(result.reset),
+  });
+
+  if (!result.allowed) {
+    setResponseHeader(event, "Retry-After", String(result.retryAfter));
+
+    throw createApiError({
+      status: 429,
+      code: "rate_limited",
+      message: `rate limit exceeded for ${group.name}, retry in ${result.retryAfter} seconds`,
+    });
+  }
+});
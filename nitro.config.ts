@@ -1,3 +1,6 @@
+// eslint-disable-next-line node/prefer-global/process
+const selfHosted = process.env.NITRO_PRESET === "node-server";
+
 // https://nitro.unjs.io/config
 export default defineNitroConfig({
   runtimeConfig: {
@@ -5,11 +8,156 @@ export default defineNitroConfig({
       token: "",
       username: "luxass",
     },
+    // the users and organizations whose repositories are discovered.
+    // every owner can have its own `token`, and the `siteUrl` showcasing its projects.
+    // their repositories define their config in `.github/mosaic.toml`,
+    // every other repository is resolved externally from luxass/luxass.
+    owners: [
+      { login: "luxass", type: "user", include: [], exclude: [] },
+    ],
+    // globs matched against `owner/name` during discovery, the last matching rule wins.
+    // prefix a rule with `!` to exclude, e.g. `["luxass/*", "!luxass/dotfiles*", "!*-archive"]`.
+    syncRules: [],
+    indexNow: {
+      // search engines are only notified when a key is set.
+      key: "",
+      // where the key file is served, defaults to `https://<host>/<key>.txt`.
+      keyLocation: "",
+      host: "luxass.dev",
+      urlTemplate: "https://luxass.dev/projects/{handle}",
+    },
+    // the token required by the admin routes, as `Authorization: Bearer <token>`.
+    adminToken: "",
+    vercel: {
+      token: "",
+      teamId: "",
+    },
+    otel: {
+      // the base url of an OTLP/HTTP collector, e.g. `http://localhost:4318`.
+      // traces are only exported when this is set.
+      endpoint: "",
+    },
+    cors: {
+      origins: [
+        "https://luxass.dev",
+        "https://*.luxass.dev",
+        // preview deployments of luxass.dev
+        "https://*.vercel.app",
+        "http://localhost:3000",
+        "http://localhost:4321",
+      ],
+      // how long browsers can cache a preflight response, in seconds.
+      maxAge: 60 * 60 * 24,
+      // the first matching group is used, requests matching no group get no cors headers.
+      groups: [
+        // editors and other tools resolve `$schema` from everywhere.
+        { pattern: "/json-schema{,.json}", methods: ["GET", "HEAD"], origins: ["*"] },
+        { pattern: "/schemas/**", methods: ["GET", "HEAD"], origins: ["*"] },
+        { pattern: "/api/**", methods: ["GET", "HEAD", "POST", "PATCH", "DELETE"] },
+      ],
+    },
+    // limits are kept in memory, so on Cloudflare they apply per isolate and not globally.
+    rateLimit: {
+      enabled: true,
+      // the header containing the client ip, e.g. `cf-connecting-ip`. only set this when mosaic is behind
+      // a proxy that overwrites the header, otherwise clients can spoof it. unset, the socket address is used.
+      ipHeader: "",
+      // the first matching group is used, requests matching no group are not limited.
+      groups: [
+        { name: "views", pattern: "/api/v1/mosaic/*/*/view", capacity: 10, refillPerMinute: 10 },
+        { name: "reports", pattern: "/api/v1/mosaic/*/*/report", capacity: 3, refillPerMinute: 1 },
+        { name: "readme", pattern: "/api/v1/mosaic/*/*/readme{,/**}", capacity: 30, refillPerMinute: 30 },
+        { name: "api", pattern: "/api/**", capacity: 120, refillPerMinute: 120 },
+      ],
+    },
+    reports: {
+      // when set, reports require a Cloudflare Turnstile token.
+      turnstileSecret: "",
+    },
+    shutdown: {
+      // how long running jobs get to finish on shutdown, in milliseconds.
+      // keep it below the grace period of the process manager, e.g. 30 seconds for docker.
+      drainTimeout: 25_000,
+    },
+    i18n: {
+      // used when none of the requested locales of a localized string are available.
+      defaultLocale: "en",
+    },
+    pagination: {
+      // the page size of admin lists, when no `?limit=` is given.
+      defaultPageSize: 50,
+      // the largest `?limit=` accepted, so a single response can't load every stored item.
+      maxPageSize: 200,
+    },
+    sync: {
+      // projects are dead-lettered after this many consecutive failed syncs.
+      maxAttempts: 5,
+      // the delay before the first retry in seconds, doubled on every failure.
+      backoffBase: 60 * 15,
+      // the maximum delay between retries in seconds.
+      backoffMax: 60 * 60 * 24,
+    },
+    shadow: {
+      // the base url of a staging instance, a sample of GET requests is mirrored to it when set.
+      url: "",
+      // the fraction of requests to mirror, between 0 and 1.
+      sampleRate: 0.1,
+    },
     worker: "http://localhost:8787",
     // eslint-disable-next-line node/prefer-global/process
     siteUrl: process.env.DEPLOY_URL ? process.env.URL : "http://localhost:3000",
   },
   preset: "cloudflare-pages",
+  storage: {
+    // when self-hosting, everything is stored next to the server output.
+    mosaic: selfHosted
+      ? {
+          driver: "fs",
+          base: "./.data/mosaic",
+        }
+      : {
+          driver: "cloudflare-kv-binding",
+          binding: "MOSAIC",
+        },
+  },
+  devStorage: {
+    mosaic: {
+      driver: "fs",
+      base: "./.data/mosaic",
+    },
+  },
+  experimental: {
+    tasks: true,
+    openAPI: true,
+    asyncContext: true,
+  },
+  openAPI: {
+    // served with the registered tags and shared components at `/api/openapi.json`, see `api/openapi.json.get.ts`
+    route: "/_openapi.json",
+    production: "runtime",
+    meta: {
+      title: "mosaic",
+      description: "An Api serving the list of projects and their details.",
+      version: "1.0.0",
+    },
+    ui: {
+      scalar: {
+        route: "/api/docs",
+        spec: {
+          url: "/api/openapi.json",
+        },
+      },
+      swagger: false,
+    },
+  },
+  // scheduled tasks only run when self-hosting, Cloudflare Pages has no cron triggers.
+  // on Pages the same schedule is run by `.github/workflows/sync.yaml`, through `POST /api/v1/admin/sync/run`.
+  scheduledTasks: {
+    // every day at 03:00
+    "0 3 * * *": ["projects:sync", "links:check", "stats:snapshot", "stack:sync"],
+    // every hour
+    "0 * * * *": ["indexnow:submit"],
+  },
   compatibilityDate: "2024-09-11",
   typescript: {
     strict: true,
@@ -0,0 +1,75 @@
+import type { ShadowDiff } from "~/utils/shadow";
+
+export default defineNitroPlugin((nitroApp) => {
+  const { shadow } = useRuntimeConfig();
+
+  if (!shadow.url) {
+    return;
+  }
+
+  nitroApp.hooks.hook("beforeResponse", (event, response) => {
+    const path = event.path;
+
+    if (
+      event.method !== "GET"
+      || !path.startsWith("/api/")
+      || path.startsWith("/api/v1/admin")
+      || Math.random() >= shadow.sampleRate
+    ) {
+      return;
+    }
+
+    const productionBody = response.body;
+
+    if (!isMirroredResponse(getResponseHeader(event, "content-type")?.toString(), productionBody)) {
+      return;
+    }
+
+    const productionStatus = getResponseStatus(event);
+
+    const mirror = async () => {
+      const diff: ShadowDiff = {
+        path,
+        requestId: event.context.requestId,
+        recordedAt: new Date().toISOString(),
+        status: { production: productionStatus },
+        differences: [],
+      };
+
+      try {
+        const res = await fetch(new URL(path, shadow.url), {
+          headers: scrubHeaders(getRequestHeaders(event)),
+          signal: AbortSignal.timeout(15_000),
+        });
+
+        diff.status.staging = res.status;
+
+        if (res.status !== productionStatus) {
+          diff.differences.push("status");
+        }
+
+        const stagingBody = res.headers.get("content-type")?.includes("json") ? await res.json() : await res.text();
+        const production = typeof productionBody === "string" ? safeParse(productionBody) : productionBody;
+
+        diffJson(production, stagingBody, "$", diff.differences);
+      } catch (err) {
+        diff.error = err instanceof Error ? err.message : String(err);
+      }
+
+      if (diff.differences.length || diff.error) {
+        await recordShadowDiff(diff);
+      }
+    };
+
+    // never delay the production response
+    event.waitUntil(mirror());
+  });
+});
+
+function safeParse(value: string): unknown {
+  try {
+    return JSON.parse(value);
+  } catch {
+    return value;
+  }
+}
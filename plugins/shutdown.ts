@@ -0,0 +1,15 @@
+export default defineNitroPlugin((nitroApp) => {
+  const { shutdown } = useRuntimeConfig();
+
+  // called by the node-server preset on SIGTERM and SIGINT, before the server is closed.
+  nitroApp.hooks.hook("close", async () => {
+    const { finished, interrupted } = await drainJobs(shutdown.drainTimeout);
+
+    if (interrupted.length) {
+      console.warn(`shutdown interrupted ${interrupted.join(", ")}, they run again on the next schedule`);
+    } else if (finished.length) {
+      // eslint-disable-next-line no-console
+      console.info(`drained ${finished.join(", ")} before shutdown`);
+    }
+  });
+});
@@ -0,0 +1,45 @@
+export default defineNitroPlugin((nitroApp) => {
+  const runtimeConfig = useRuntimeConfig();
+
+  nitroApp.hooks.hook("request", (event) => {
+    const { traceId, parentSpanId } = resolveTraceContext(event);
+    const requestId = getRequestHeader(event, "x-request-id") || traceId;
+
+    event.context.requestId = requestId;
+    event.context.traceId = traceId;
+    event.context.rootSpan = createSpan(traceId, `${event.method} ${event.path.split("?")[0]}`, {
+      "http.method": event.method,
+      "http.target": event.path,
+      "request.id": requestId,
+    }, parentSpanId, "server");
+    event.context.spans = [];
+
+    setResponseHeader(event, "x-request-id", requestId);
+  });
+
+  nitroApp.hooks.hook("afterResponse", (event) => {
+    const rootSpan = event.context.rootSpan;
+    if (!rootSpan) {
+      return;
+    }
+
+    rootSpan.endTime = Date.now();
+    rootSpan.attributes["http.status_code"] = getResponseStatus(event);
+
+    if (import.meta.dev) {
+      // eslint-disable-next-line no-console
+      console.info(`[${event.context.requestId}] ${rootSpan.name} ${getResponseStatus(event)} in ${rootSpan.endTime - rootSpan.startTime}ms`);
+    }
+
+    if (!runtimeConfig.otel.endpoint) {
+      return;
+    }
+
+    // the response is already sent, but the runtime has to be kept alive until the spans are exported.
+    event.waitUntil(
+      exportSpans(runtimeConfig.otel.endpoint, [rootSpan, ...(event.context.spans || [])]).catch((err) => {
+        console.error("Error exporting spans:", err);
+      }),
+    );
+  });
+});
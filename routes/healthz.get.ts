@@ -0,0 +1,14 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["meta"],
+    summary: "Liveness",
+    description: "Returns `ok` as long as the server is running.",
+    responses: {
+      "200": {
+        description: "The server is alive",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(() => ({ status: "ok" }));
@@ -1 +1,14 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["meta"],
+    summary: "Hello world",
+    description: "Returns `Hello, World!`.",
+    responses: {
+      "200": {
+        description: "Hello, World!",
+      },
+    },
+  },
+});
+
 export default defineEventHandler(() => "Hello, World!");
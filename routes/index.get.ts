@@ -1,3 +1,21 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["meta"],
+    summary: "Landing page",
+    description: "Renders the landing page.",
+    responses: {
+      "200": {
+        description: "The landing page",
+        content: {
+          "text/html": {
+            schema: { type: "string" },
+          },
+        },
+      },
+    },
+  },
+});
+
 function html(strings: TemplateStringsArray, ...values: any[]) {
   return String.raw(strings, ...values);
 }
@@ -1,27 +1,25 @@
-import { z } from "zod";
-import { zodToJsonSchema } from "zod-to-json-schema";
+defineRouteMeta({
+  openAPI: {
+    tags: ["schema"],
+    summary: "Get JSON schema",
+    description: "Returns the JSON schema of version 1 of `mosaic.toml`, which existing configs point their `$schema` at. Use `/schemas/mosaic/v{version}.json` for other versions.",
+    responses: {
+      "200": {
+        description: "The JSON schema",
+      },
+    },
+  },
+});
 
 export default defineCachedEventHandler(async (event) => {
-  const jsonSchema = zodToJsonSchema(
-    MOSAIC_SCHEMA.merge(
-      z.object({
-        $schema: z
-          .string({
-            description: "Ignore this, it's just for editors.",
-          })
-          .default("https://mosaic.luxass.dev/json-schema"),
-      }),
-    ),
-  );
-
   setResponseHeaders(event, {
     "Content-Type": "application/json",
-    "Access-Control-Allow-Origin": "*",
     "Cache-Control": "public, s-maxage=3600, must-revalidate",
     "Content-Disposition": "inline",
   });
 
-  return jsonSchema;
+  // this url predates versioned schemas, so it keeps serving the format the configs using it were written for.
+  return getMosaicJsonSchema(1);
 }, {
   maxAge: 60 * 60, // 1 hour
   swr: true,
@@ -1 +1,14 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["meta"],
+    summary: "Ping",
+    description: "Returns `pong`.",
+    responses: {
+      "200": {
+        description: "pong",
+      },
+    },
+  },
+});
+
 export default defineEventHandler(async () => "pong");
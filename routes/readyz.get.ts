@@ -0,0 +1,95 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["meta"],
+    summary: "Readiness",
+    description: "Checks the GitHub token, the remaining rate limit and the storage backends. Unavailable while the instance is shutting down.",
+    responses: {
+      "200": {
+        description: "Every dependency is ready",
+      },
+      "503": {
+        description: "At least one dependency is not ready",
+      },
+    },
+  },
+});
+
+interface DependencyStatus {
+  ok: boolean;
+  latency: number;
+  message?: string;
+  [key: string]: unknown;
+}
+
+async function check(fn: () => Promise<Omit<DependencyStatus, "latency">>): Promise<DependencyStatus> {
+  const start = Date.now();
+  try {
+    return { ...(await fn()), latency: Date.now() - start };
+  } catch (err) {
+    return {
+      ok: false,
+      latency: Date.now() - start,
+      message: err instanceof Error ? err.message : "unknown error",
+    };
+  }
+}
+
+// readiness is probed constantly, so the storage is only read. a write per probe would burn through
+// the KV write quota, and reading a missing key still has to reach the backend.
+async function checkStorage(base: string): Promise<Omit<DependencyStatus, "latency">> {
+  await useStorage(base).hasItem("readyz");
+
+  return { ok: true };
+}
+
+export default defineEventHandler(async (event) => {
+  const runtimeConfig = useRuntimeConfig();
+
+  const [github, storage, cache] = await Promise.all([
+    check(async () => {
+      const res = await fetch("https://api.github.com/rate_limit", {
+        headers: {
+          "Authorization": `Bearer ${runtimeConfig.github.token}`,
+          "Content-Type": "application/vnd.github+json",
+          "X-GitHub-Api-Version": "2022-11-28",
+        },
+      });
+
+      if (res.status === 401) {
+        return { ok: false, message: "github token is invalid or expired" };
+      }
+
+      if (!res.ok) {
+        return { ok: false, message: `github responded with ${res.status}` };
+      }
+
+      const { resources } = await res.json();
+      const remaining: number = resources.core.remaining;
+
+      return {
+        ok: remaining > 0,
+        message: remaining > 0 ? undefined : "github rate limit is exhausted",
+        remaining,
+        limit: resources.core.limit,
+        reset: new Date(resources.core.reset * 1000).toISOString(),
+        graphqlRemaining: resources.graphql?.remaining,
+      };
+    }),
+    check(() => checkStorage("mosaic")),
+    check(() => checkStorage("cache")),
+  ]);
+
+  const ok = github.ok && storage.ok && cache.ok && !isDraining();
+
+  setResponseStatus(event, ok ? 200 : 503);
+  setResponseHeader(event, "Cache-Control", "no-store");
+
+  return {
+    status: isDraining() ? "draining" : ok ? "ok" : "unavailable",
+    dependencies: {
+      github,
+      storage,
+      cache,
+    },
+  };
+});
@@ -0,0 +1,48 @@
+defineRouteMeta({
+  openAPI: {
+    tags: ["schema"],
+    summary: "Get versioned JSON schema",
+    description: "Returns the JSON schema of a version of `mosaic.toml`, so editors validate older configs against the format they were written for.",
+    parameters: [
+      {
+        in: "path",
+        name: "version",
+        required: true,
+        description: "The config version as `v{version}.json`, e.g. `v1.json`.",
+        schema: { type: "string" },
+      },
+    ],
+    responses: {
+      "200": {
+        description: "The JSON schema",
+      },
+      "404": {
+        $ref: "#/components/responses/NotFound",
+      },
+    },
+  },
+});
+
+export default defineCachedEventHandler(async (event) => {
+  const match = getRouterParam(event, "version")?.match(/^v(\d+)\.json$/);
+  const jsonSchema = match ? getMosaicJsonSchema(Number(match[1])) : undefined;
+
+  if (!jsonSchema) {
+    throw createApiError({
+      status: 404,
+      code: "not_found",
+      message: `unknown schema version, expected one of ${getSchemaVersions().map((version) => `v${version}.json`).join(", ")}`,
+    });
+  }
+
+  setResponseHeaders(event, {
+    "Content-Type": "application/json",
+    "Cache-Control": "public, s-maxage=3600, must-revalidate",
+    "Content-Disposition": "inline",
+  });
+
+  return jsonSchema;
+}, {
+  maxAge: 60 * 60, // 1 hour
+  swr: true,
+});
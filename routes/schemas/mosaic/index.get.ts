@@ -0,0 +1,24 @@
+import { CURRENT_CONFIG_VERSION } from "~/core/migrations";
+
+defineRouteMeta({
+  openAPI: {
+    tags: ["schema"],
+    summary: "List JSON schema versions",
+    description: "Lists every supported version of `mosaic.toml`, and the url of its JSON schema.",
+    responses: {
+      "200": {
+        description: "The schema versions",
+      },
+    },
+  },
+});
+
+export default defineEventHandler(() => {
+  return {
+    current: CURRENT_CONFIG_VERSION,
+    versions: getSchemaVersions().map((version) => ({
+      version,
+      url: getSchemaUrl(version),
+    })),
+  };
+});
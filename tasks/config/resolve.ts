@@ -0,0 +1,18 @@
+import type { ConfigTaskPayload } from "~/utils/local-config";
+
+export default defineTask({
+  meta: {
+    name: "config:resolve",
+    description: "Resolve a config from a local path or an `owner/name` repository, as the api would see it",
+  },
+  async run({ payload }) {
+    const { path, repository } = payload as ConfigTaskPayload;
+    const slug = parseRepositorySlug(repository);
+
+    if (!path && slug) {
+      return { result: await resolveMosaicConfig(...slug) };
+    }
+
+    return { result: await parseLocalConfig(path || ".") };
+  },
+});
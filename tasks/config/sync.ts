@@ -0,0 +1,66 @@
+import type { ConfigTaskPayload } from "~/utils/local-config";
+
+export default defineTask({
+  meta: {
+    name: "config:sync",
+    description: "Show what a sync of a repository would write, without writing it. Pass `path` to use a local config instead of the pushed one",
+  },
+  async run({ payload }) {
+    const { path, repository } = payload as ConfigTaskPayload;
+    const slug = parseRepositorySlug(repository);
+
+    if (!slug) {
+      return { result: { error: "a repository is required, as `owner/name`" } };
+    }
+
+    const [owner, name] = slug;
+
+    // read what is stored before resolving anything, so the diff is against the state before this run.
+    const [storedWarnings, storedStack, storedLicense] = await Promise.all([
+      getProjectConfigWarnings(owner, name),
+      getProjectStack(owner, name),
+      getProjectLicense(owner, name),
+    ]);
+
+    const config = path ? await parseLocalConfig(path) : await resolveMosaicConfig(owner, name);
+    const resolved = "success" in config
+      ? (config.success ? { content: config.data, warnings: config.warnings } : undefined)
+      : (config.type === "resolved" ? { content: config.content, warnings: config.warnings } : undefined);
+
+    if (!resolved) {
+      return { result: { error: "config could not be resolved", config } };
+    }
+
+    const [repositoryNode, manifests] = await Promise.all([
+      getRepository(owner, name),
+      getManifests(owner, name),
+    ]);
+
+    const license = await detectProjectLicense(owner, name, resolved.content.project.license, repositoryNode?.licenseInfo?.spdxId);
+
+    // the values the sync would store, compared with what is stored now.
+    const planned = {
+      warnings: resolved.warnings,
+      license: license?.spdxId ?? null,
+      stacks: detectStacks(manifests),
+      dependencies: listDependencies(manifests),
+    };
+
+    const current = {
+      warnings: storedWarnings?.warnings ?? [],
+      license: storedLicense?.spdxId ?? null,
+      stacks: storedStack?.stacks ?? [],
+      dependencies: storedStack?.dependencies ?? [],
+    };
+
+    return {
+      result: {
+        dryRun: true,
+        repository: `${owner}/${name}`,
+        config: resolved.content,
+        changes: diffJson(current, planned),
+        planned,
+      },
+    };
+  },
+});
@@ -0,0 +1,31 @@
+import type { ConfigTaskPayload } from "~/utils/local-config";
+
+export default defineTask({
+  meta: {
+    name: "config:validate",
+    description: "Validate a local mosaic.toml, e.g. `nitro task run config:validate --payload '{\"path\":\"../my-repo\"}'`",
+  },
+  async run({ payload }) {
+    const { path = "." } = payload as ConfigTaskPayload;
+    const parsed = await parseLocalConfig(path);
+
+    if (!parsed.success) {
+      return {
+        result: {
+          valid: false,
+          path: parsed.path,
+          message: parsed.message,
+          details: parsed.details,
+        },
+      };
+    }
+
+    return {
+      result: {
+        valid: true,
+        path: parsed.path,
+        warnings: parsed.warnings,
+      },
+    };
+  },
+});
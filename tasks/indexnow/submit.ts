@@ -0,0 +1,46 @@
+export default defineTask({
+  meta: {
+    name: "indexnow:submit",
+    description: "Notify search engines about new and updated project pages",
+  },
+  async run({ payload }) {
+    const { indexNow } = useRuntimeConfig();
+
+    if (!indexNow.key) {
+      return { result: "indexnow is not configured" };
+    }
+
+    const result = await runJob("indexnow:submit", payload.scheduledTime ? "scheduled" : "manual", async () => {
+      // handles come from the last `projects:sync`, so configs aren't resolved again every hour.
+      // projects that haven't been synced yet are submitted once they are.
+      const [repositories, synced] = await Promise.all([getOwnerRepositories(), getSyncedProjects()]);
+
+      const projects = repositories
+        .filter((repository) => !repository.isArchived)
+        .flatMap((repository) => {
+          const project = synced.get(repository.nameWithOwner.toLowerCase());
+          return project ? [{ handle: project.handle, pushedAt: repository.pushedAt }] : [];
+        });
+
+      // projects past the limit are submitted on the next run.
+      const changed = (await getChangedProjects(projects))
+        .slice(0, INDEXNOW_MAX_URLS);
+      const urls = changed.map((project) => projectPageUrl(project.handle));
+
+      const accepted = await submitToIndexNow(urls);
+
+      // only accepted projects are remembered, otherwise they'd never be submitted again.
+      if (accepted) {
+        await markProjectsSubmitted(changed);
+      }
+
+      return {
+        submitted: urls.length,
+        accepted,
+        urls,
+      };
+    });
+
+    return { result };
+  },
+});
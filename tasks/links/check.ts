@@ -0,0 +1,61 @@
+export default defineTask({
+  meta: {
+    name: "links:check",
+    description: "Re-check the website, README and docs links of every discovered project",
+  },
+  async run({ payload }) {
+    const result = await runJob("links:check", payload.scheduledTime ? "scheduled" : "manual", async () => {
+      const repositories = (await getOwnerRepositories()).filter((repository) => !repository.isArchived);
+
+      let checked = 0;
+      let broken = 0;
+      let failed = 0;
+      let skipped = 0;
+      for (const { owner, name: repository } of repositories) {
+        if (isDraining()) {
+          break;
+        }
+
+        if (!await shouldSync("links:check", owner, repository)) {
+          skipped++;
+          continue;
+        }
+
+        try {
+          const checks = await checkProjectLinks(owner, repository);
+
+          if (checks) {
+            checked++;
+            broken += checks.broken;
+
+            if (checks.broken > 0) {
+              await addInboxItem({
+                source: "link_check",
+                title: `${checks.broken} broken links in ${owner}/${repository}`,
+                owner,
+                repository,
+                dedupeKey: `link_check:${owner}:${repository}`,
+                data: checks.links.filter((link) => !link.ok),
+              });
+            }
+          }
+
+          await clearSyncFailure("links:check", owner, repository);
+        } catch (err) {
+          failed++;
+          await recordSyncFailure("links:check", owner, repository, err);
+        }
+      }
+
+      return {
+        repositories: repositories.length,
+        checked,
+        broken,
+        failed,
+        skipped,
+      };
+    });
+
+    return { result };
+  },
+});
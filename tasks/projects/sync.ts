@@ -0,0 +1,45 @@
+export default defineTask({
+  meta: {
+    name: "projects:sync",
+    description: "Resolve the config of every discovered repository, and store what is derived from it",
+  },
+  async run({ payload }) {
+    const result = await runJob("projects:sync", payload.scheduledTime ? "scheduled" : "manual", async () => {
+      const repositories = (await getOwnerRepositories()).filter((repository) => !repository.isArchived);
+
+      let synced = 0;
+      let failed = 0;
+      let skipped = 0;
+      await mapWithConcurrency(repositories, 5, async ({ owner, name }) => {
+        if (isDraining()) {
+          return;
+        }
+
+        if (!await shouldSync("projects:sync", owner, name)) {
+          skipped++;
+          return;
+        }
+
+        try {
+          if (await syncProject(owner, name)) {
+            synced++;
+          }
+
+          await clearSyncFailure("projects:sync", owner, name);
+        } catch (err) {
+          failed++;
+          await recordSyncFailure("projects:sync", owner, name, err);
+        }
+      });
+
+      return {
+        repositories: repositories.length,
+        synced,
+        failed,
+        skipped,
+      };
+    });
+
+    return { result };
+  },
+});
@@ -0,0 +1,45 @@
+export default defineTask({
+  meta: {
+    name: "stack:sync",
+    description: "Parse the dependency manifests of every discovered project",
+  },
+  async run({ payload }) {
+    const result = await runJob("stack:sync", payload.scheduledTime ? "scheduled" : "manual", async () => {
+      const repositories = (await getOwnerRepositories()).filter((repository) => !repository.isArchived);
+
+      let synced = 0;
+      let failed = 0;
+      let skipped = 0;
+      await mapWithConcurrency(repositories, 5, async ({ owner, name }) => {
+        if (isDraining()) {
+          return;
+        }
+
+        if (!await shouldSync("stack:sync", owner, name)) {
+          skipped++;
+          return;
+        }
+
+        try {
+          if (await syncProjectStack(owner, name)) {
+            synced++;
+          }
+
+          await clearSyncFailure("stack:sync", owner, name);
+        } catch (err) {
+          failed++;
+          await recordSyncFailure("stack:sync", owner, name, err);
+        }
+      });
+
+      return {
+        repositories: repositories.length,
+        synced,
+        failed,
+        skipped,
+      };
+    });
+
+    return { result };
+  },
+});
@@ -0,0 +1,32 @@
+export default defineTask({
+  meta: {
+    name: "stats:snapshot",
+    description: "Record the daily stars, forks and open issues of every discovered repository",
+  },
+  async run({ payload }) {
+    const result = await runJob("stats:snapshot", payload.scheduledTime ? "scheduled" : "manual", async () => {
+      const repositories = await getOwnerRepositories();
+
+      const recorded = await mapWithConcurrency(repositories, 10, async (repository) => {
+        if (isDraining()) {
+          return false;
+        }
+
+        await recordStatsSnapshot(repository.owner, repository.name, {
+          stars: repository.stars,
+          forks: repository.forks,
+          openIssues: repository.openIssues,
+        });
+
+        return true;
+      });
+
+      return {
+        repositories: repositories.length,
+        recorded: recorded.filter(Boolean).length,
+      };
+    });
+
+    return { result };
+  },
+});
@@ -0,0 +1,65 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { getProjectCard } from "../utils/card";
+import { localize } from "../utils/i18n";
+
+const repository = {
+  name: "mosaic",
+  nameWithOwner: "luxass/mosaic",
+  description: "A mosaic of projects",
+  url: "https://github.com/luxass/mosaic",
+  homepageUrl: null,
+  isPrivate: false,
+  stargazerCount: 42,
+  languages: { nodes: [{ name: "TypeScript", color: "#3178c6" }] },
+  openGraphImageUrl: "https://opengraph.githubassets.com/mosaic",
+};
+
+function resolved(project: Record<string, unknown> = {}) {
+  return { type: "resolved", content: { project: { name: "Mosaic", ignore: false, ...project } } };
+}
+
+beforeEach(() => {
+  vi.stubGlobal("useRuntimeConfig", () => ({ i18n: { defaultLocale: "en" } }));
+  vi.stubGlobal("localize", localize);
+  vi.stubGlobal("getRepository", async () => repository);
+  vi.stubGlobal("resolveMosaicConfig", async () => resolved());
+});
+
+describe("getProjectCard", () => {
+  it("builds the card of a project", async () => {
+    vi.stubGlobal("resolveMosaicConfig", async () => resolved({ description: { en: "Projects", da: "Projekter" } }));
+
+    expect(await getProjectCard("luxass", "mosaic", ["da"])).toEqual({
+      name: "Mosaic",
+      nameWithOwner: "luxass/mosaic",
+      description: "Projekter",
+      url: "https://github.com/luxass/mosaic",
+      website: undefined,
+      stars: 42,
+      languages: [{ name: "TypeScript", color: "#3178c6" }],
+      ogImage: "https://opengraph.githubassets.com/mosaic",
+    });
+  });
+
+  it("returns `undefined` for ignored projects", async () => {
+    vi.stubGlobal("resolveMosaicConfig", async () => resolved({ ignore: true }));
+
+    expect(await getProjectCard("luxass", "mosaic")).toBeUndefined();
+  });
+
+  it("returns `undefined` for repositories without a resolved config", async () => {
+    vi.stubGlobal("resolveMosaicConfig", async () => ({ type: "not_found" }));
+    expect(await getProjectCard("luxass", "mosaic")).toBeUndefined();
+
+    vi.stubGlobal("resolveMosaicConfig", async () => ({ type: "error", message: "invalid config" }));
+    expect(await getProjectCard("luxass", "mosaic")).toBeUndefined();
+  });
+
+  it("returns `undefined` for private and missing repositories", async () => {
+    vi.stubGlobal("getRepository", async () => ({ ...repository, isPrivate: true }));
+    expect(await getProjectCard("luxass", "mosaic")).toBeUndefined();
+
+    vi.stubGlobal("getRepository", async () => undefined);
+    expect(await getProjectCard("luxass", "mosaic")).toBeUndefined();
+  });
+});
@@ -0,0 +1,85 @@
+import { describe, expect, it } from "vitest";
+import { parseJsonArrayStream, toCsvValue } from "../utils/export";
+
+function toStream(chunks: string[]): ReadableStream<Uint8Array> {
+  const encoder = new TextEncoder();
+
+  return new ReadableStream({
+    start(controller) {
+      for (const chunk of chunks) {
+        controller.enqueue(encoder.encode(chunk));
+      }
+      controller.close();
+    },
+  });
+}
+
+async function collect(chunks: string[]): Promise<unknown[]> {
+  const items: unknown[] = [];
+  for await (const item of parseJsonArrayStream(toStream(chunks))) {
+    items.push(item);
+  }
+  return items;
+}
+
+describe("parseJsonArrayStream", () => {
+  it("parses a top-level array", async () => {
+    expect(await collect(["[{\"a\":1},{\"a\":2}]"])).toEqual([{ a: 1 }, { a: 2 }]);
+  });
+
+  it("parses items split across chunks", async () => {
+    expect(await collect(["[{\"a\"", ":1},{\"a\":", "2}", "]"])).toEqual([{ a: 1 }, { a: 2 }]);
+  });
+
+  it("parses the first array inside a top-level object", async () => {
+    expect(await collect(["{\"projects\":[1,{\"b\":[1,2]}],\"total\":2}"])).toEqual([1, { b: [1, 2] }]);
+  });
+
+  it("ignores brackets, commas and escaped quotes inside strings", async () => {
+    expect(await collect(["[\"a,]b\",\"c\\\"}d\"]"])).toEqual(["a,]b", "c\"}d"]);
+  });
+
+  it("parses an empty array", async () => {
+    expect(await collect(["[", "]"])).toEqual([]);
+  });
+
+  it("cancels and unlocks the body when reading stops early", async () => {
+    const body = toStream(["[1,", "2,", "3]"]);
+
+    for await (const item of parseJsonArrayStream(body)) {
+      expect(item).toBe(1);
+      break;
+    }
+
+    expect(body.locked).toBe(false);
+    await expect(body.cancel()).resolves.toBeUndefined();
+  });
+});
+
+describe("toCsvValue", () => {
+  it("formats empty values, primitives and objects", () => {
+    expect(toCsvValue(undefined)).toBe("");
+    expect(toCsvValue(null)).toBe("");
+    expect(toCsvValue(42)).toBe("42");
+    expect(toCsvValue(true)).toBe("true");
+    expect(toCsvValue(["a", "b"])).toBe("\"[\"\"a\"\",\"\"b\"\"]\"");
+  });
+
+  it.each(["=", "+", "-", "@", "\t", "\r"])("escapes values starting with %j as formulas", (prefix) => {
+    expect(toCsvValue(`${prefix}SUM(A1)`)).toMatch(/^"?'/);
+  });
+
+  it("escapes formulas before quoting them", () => {
+    expect(toCsvValue("=1+1")).toBe("'=1+1");
+    expect(toCsvValue("-1,5")).toBe("\"'-1,5\"");
+    expect(toCsvValue("\r\nHYPERLINK()")).toBe("\"'\r\nHYPERLINK()\"");
+  });
+
+  it("quotes values as described in RFC 4180", () => {
+    expect(toCsvValue("mosaic")).toBe("mosaic");
+    expect(toCsvValue("a,b")).toBe("\"a,b\"");
+    expect(toCsvValue("say \"hi\"")).toBe("\"say \"\"hi\"\"\"");
+    expect(toCsvValue("line\nbreak")).toBe("\"line\nbreak\"");
+    expect(toCsvValue("line\r\nbreak")).toBe("\"line\r\nbreak\"");
+  });
+});
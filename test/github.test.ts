@@ -0,0 +1,33 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import { getRetryAfter } from "../utils/github";
+
+beforeEach(() => {
+  vi.useFakeTimers();
+  vi.setSystemTime(new Date("2026-01-01T00:00:00.000Z"));
+});
+
+afterEach(() => {
+  vi.useRealTimers();
+});
+
+describe("getRetryAfter", () => {
+  it("reads seconds", () => {
+    expect(getRetryAfter({ "retry-after": "120" })).toBe(120);
+    expect(getRetryAfter(new Headers({ "retry-after": "0" }))).toBe(0);
+  });
+
+  it("reads http dates", () => {
+    expect(getRetryAfter({ "retry-after": "Thu, 01 Jan 2026 00:01:30 GMT" })).toBe(90);
+    expect(getRetryAfter({ "retry-after": "Wed, 31 Dec 2025 23:59:00 GMT" })).toBe(0);
+  });
+
+  it("falls back to the rate limit reset", () => {
+    expect(getRetryAfter({ "x-ratelimit-reset": String(Date.parse("2026-01-01T00:05:00.000Z") / 1000) })).toBe(300);
+  });
+
+  it("returns undefined for invalid values", () => {
+    expect(getRetryAfter({ "retry-after": "soon" })).toBeUndefined();
+    expect(getRetryAfter({ "x-ratelimit-reset": "later" })).toBeUndefined();
+    expect(getRetryAfter({})).toBeUndefined();
+  });
+});
@@ -0,0 +1,55 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { getRequestLocales, localize } from "../utils/i18n";
+
+interface FakeEvent {
+  query: Record<string, string>;
+  headers: Record<string, string>;
+}
+
+function createEvent(query: Record<string, string> = {}, headers: Record<string, string> = {}): any {
+  return { query, headers } satisfies FakeEvent;
+}
+
+beforeEach(() => {
+  vi.stubGlobal("useRuntimeConfig", () => ({ i18n: { defaultLocale: "en" } }));
+  vi.stubGlobal("getQuery", (event: FakeEvent) => event.query);
+  vi.stubGlobal("getRequestHeader", (event: FakeEvent, name: string) => event.headers[name]);
+});
+
+describe("getRequestLocales", () => {
+  it("prefers `?lang=` over `Accept-Language`", () => {
+    expect(getRequestLocales(createEvent({ lang: "da,EN" }, { "accept-language": "fr" }))).toEqual(["da", "en"]);
+  });
+
+  it("sorts `Accept-Language` by quality and drops wildcards and refused locales", () => {
+    const event = createEvent({}, { "accept-language": "en;q=0.5, da-DK, *;q=0.1, fr;q=0" });
+    expect(getRequestLocales(event)).toEqual(["da-dk", "en"]);
+  });
+
+  it("returns no locales without a preference", () => {
+    expect(getRequestLocales(createEvent())).toEqual([]);
+  });
+});
+
+describe("localize", () => {
+  const description = { "en": "Hello", "da": "Hej", "pt-BR": "Olá" };
+
+  it("returns plain strings as is", () => {
+    expect(localize("Hello", ["da"])).toBe("Hello");
+    expect(localize(undefined, ["da"])).toBeUndefined();
+  });
+
+  it("picks the first requested locale with a translation", () => {
+    expect(localize(description, ["fr", "da"])).toBe("Hej");
+    expect(localize(description, ["pt-br"])).toBe("Olá");
+  });
+
+  it("falls back from a region to its language", () => {
+    expect(localize(description, ["da-dk"])).toBe("Hej");
+  });
+
+  it("falls back to the default locale, then the first translation", () => {
+    expect(localize(description, ["fr"])).toBe("Hello");
+    expect(localize({ da: "Hej", de: "Hallo" }, ["fr"])).toBe("Hej");
+  });
+});
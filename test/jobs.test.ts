@@ -0,0 +1,102 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import { clearSyncFailure, recordSyncFailure, shouldSync } from "../utils/jobs";
+
+const addInboxItem = vi.fn();
+
+beforeEach(() => {
+  const items = new Map<string, unknown>();
+
+  vi.useFakeTimers();
+  vi.setSystemTime(new Date("2026-01-01T00:00:00.000Z"));
+
+  vi.stubGlobal("useRuntimeConfig", () => ({
+    sync: { maxAttempts: 3, backoffBase: 60, backoffMax: 90 },
+  }));
+  vi.stubGlobal("useStorage", () => ({
+    getItem: async (key: string) => items.get(key) ?? null,
+    setItem: async (key: string, value: unknown) => {
+      items.set(key, value);
+    },
+    removeItem: async (key: string) => {
+      items.delete(key);
+    },
+  }));
+  vi.stubGlobal("addInboxItem", addInboxItem);
+});
+
+afterEach(() => {
+  vi.useRealTimers();
+  addInboxItem.mockReset();
+});
+
+describe("recordSyncFailure", () => {
+  it("backs off exponentially up to the maximum", async () => {
+    const first = await recordSyncFailure("stack:sync", "luxass", "mosaic", new Error("invalid config"));
+    expect(first).toMatchObject({
+      count: 1,
+      error: "invalid config",
+      firstFailedAt: "2026-01-01T00:00:00.000Z",
+      nextRetryAt: "2026-01-01T00:01:00.000Z",
+      deadLettered: false,
+    });
+
+    vi.setSystemTime(new Date("2026-01-01T00:01:00.000Z"));
+    const second = await recordSyncFailure("stack:sync", "luxass", "mosaic", new Error("invalid config"));
+    expect(second).toMatchObject({
+      count: 2,
+      firstFailedAt: "2026-01-01T00:00:00.000Z",
+      lastFailedAt: "2026-01-01T00:01:00.000Z",
+      // 120 seconds, capped at `backoffMax`.
+      nextRetryAt: "2026-01-01T00:02:30.000Z",
+      deadLettered: false,
+    });
+    expect(addInboxItem).not.toHaveBeenCalled();
+  });
+
+  it("dead-letters the project after the maximum attempts", async () => {
+    await recordSyncFailure("stack:sync", "luxass", "mosaic", "timeout");
+    await recordSyncFailure("stack:sync", "luxass", "mosaic", "timeout");
+    const failure = await recordSyncFailure("stack:sync", "luxass", "mosaic", "timeout");
+
+    expect(failure).toMatchObject({ count: 3, nextRetryAt: null, deadLettered: true });
+    expect(addInboxItem).toHaveBeenCalledOnce();
+    expect(addInboxItem).toHaveBeenCalledWith(expect.objectContaining({
+      dedupeKey: "sync:stack:sync:luxass:mosaic",
+    }));
+
+    // the project is only reported once it is dead-lettered.
+    await recordSyncFailure("stack:sync", "luxass", "mosaic", "timeout");
+    expect(addInboxItem).toHaveBeenCalledOnce();
+  });
+});
+
+describe("shouldSync", () => {
+  it("syncs projects that have not failed", async () => {
+    expect(await shouldSync("stack:sync", "luxass", "mosaic")).toBe(true);
+  });
+
+  it("waits for the next retry", async () => {
+    await recordSyncFailure("stack:sync", "luxass", "mosaic", "timeout");
+    expect(await shouldSync("stack:sync", "luxass", "mosaic")).toBe(false);
+
+    vi.setSystemTime(new Date("2026-01-01T00:01:00.000Z"));
+    expect(await shouldSync("stack:sync", "luxass", "mosaic")).toBe(true);
+  });
+
+  it("only holds back the job that failed", async () => {
+    await recordSyncFailure("stack:sync", "luxass", "mosaic", "timeout");
+    expect(await shouldSync("links:check", "luxass", "mosaic")).toBe(true);
+  });
+
+  it("skips dead-lettered projects until the failure is cleared", async () => {
+    for (let i = 0; i < 3; i++) {
+      await recordSyncFailure("stack:sync", "luxass", "mosaic", "timeout");
+    }
+
+    vi.setSystemTime(new Date("2027-01-01T00:00:00.000Z"));
+    expect(await shouldSync("stack:sync", "luxass", "mosaic")).toBe(false);
+
+    await clearSyncFailure("stack:sync", "luxass", "mosaic");
+    expect(await shouldSync("stack:sync", "luxass", "mosaic")).toBe(true);
+  });
+});
@@ -0,0 +1,24 @@
+import { describe, expect, it } from "vitest";
+import { detectLicense } from "../utils/license";
+
+describe("detectLicense", () => {
+  it("detects common licenses", () => {
+    expect(detectLicense("MIT License\n\nPermission is hereby granted, free of charge, to any person obtaining a copy")).toBe("MIT");
+    expect(detectLicense("Apache License\n                           Version 2.0, January 2004")).toBe("Apache-2.0");
+    expect(detectLicense("This is free and unencumbered software released into the public domain.")).toBe("Unlicense");
+  });
+
+  it("tells apart licenses sharing the same phrases", () => {
+    expect(detectLicense("GNU LESSER GENERAL PUBLIC LICENSE\n  Version 3, 29 June 2007")).toBe("LGPL-3.0");
+    expect(detectLicense("GNU GENERAL PUBLIC LICENSE\n  Version 3, 29 June 2007")).toBe("GPL-3.0");
+    expect(detectLicense("GNU GENERAL PUBLIC LICENSE\n  Version 2, June 1991")).toBe("GPL-2.0");
+
+    const bsd2 = "Redistribution and use in source and binary forms, with or without modification, are permitted";
+    expect(detectLicense(bsd2)).toBe("BSD-2-Clause");
+    expect(detectLicense(`${bsd2}\n3. Neither the name of the copyright holder`)).toBe("BSD-3-Clause");
+  });
+
+  it("returns `undefined` for unknown licenses", () => {
+    expect(detectLicense("All rights reserved.")).toBeUndefined();
+  });
+});
@@ -0,0 +1,81 @@
+import type { ConfigMigration } from "../core/migrations";
+import { describe, expect, it } from "vitest";
+import { CURRENT_CONFIG_VERSION, migrateMosaicConfig, renameKey } from "../core/migrations";
+
+// a fixture, so the migrations are tested without deprecating a real key.
+const migrations: ConfigMigration[] = [
+  {
+    from: 1,
+    to: 2,
+    migrate(config, warn) {
+      renameKey(config.project, "oldName", "newName", "project", "2027-04-01", warn);
+
+      for (const [name, override] of Object.entries<Record<string, any>>(config.workspace?.overrides ?? {})) {
+        renameKey(override?.project, "oldName", "newName", `workspace.overrides.${name}.project`, "2027-04-01", warn);
+      }
+    },
+  },
+];
+
+describe("migrateMosaicConfig", () => {
+  it("renames deprecated keys", () => {
+    const raw = { project: { name: "mosaic", oldName: true } };
+    const result = migrateMosaicConfig(raw, migrations);
+
+    expect(result).toEqual({
+      success: true,
+      version: 1,
+      config: { version: 2, project: { name: "mosaic", newName: true } },
+      warnings: [
+        {
+          key: "project.oldName",
+          replacement: "project.newName",
+          message: "`project.oldName` is deprecated, use `project.newName` instead.",
+          removeAfter: "2027-04-01",
+        },
+      ],
+    });
+
+    // the raw config is left untouched.
+    expect(raw.project.oldName).toBe(true);
+  });
+
+  it("renames keys in workspace overrides", () => {
+    const result = migrateMosaicConfig({ workspace: { overrides: { docs: { project: { oldName: false } } } } }, migrations);
+
+    expect(result.success && result.config.workspace.overrides.docs.project).toEqual({ newName: false });
+    expect(result.success && result.warnings.map((warning) => warning.key)).toEqual(["workspace.overrides.docs.project.oldName"]);
+  });
+
+  it("lets the new key win when both are set", () => {
+    const result = migrateMosaicConfig({ project: { oldName: true, newName: false } }, migrations);
+
+    expect(result.success && result.config.project).toEqual({ newName: false });
+  });
+
+  it("leaves current configs untouched", () => {
+    const config = { version: 2, project: { newName: true } };
+
+    expect(migrateMosaicConfig(config, migrations)).toEqual({ success: true, config, version: 2, warnings: [] });
+  });
+
+  it("rejects unsupported versions", () => {
+    expect(migrateMosaicConfig({ version: 3 }, migrations)).toEqual({
+      success: false,
+      message: "error resolving config due to unsupported version 3, expected 1 to 2",
+    });
+    expect(migrateMosaicConfig({ version: "2" }, migrations).success).toBe(false);
+  });
+
+  it("has no migrations in the current format", () => {
+    const config = { project: { name: "mosaic" } };
+
+    expect(CURRENT_CONFIG_VERSION).toBe(1);
+    expect(migrateMosaicConfig(config)).toEqual({
+      success: true,
+      config: { version: 1, project: { name: "mosaic" } },
+      version: 1,
+      warnings: [],
+    });
+  });
+});
@@ -0,0 +1,59 @@
+import { readdirSync, readFileSync } from "node:fs";
+import { join } from "node:path";
+import { fileURLToPath } from "node:url";
+import { describe, expect, it } from "vitest";
+import { findUnregisteredTags } from "../utils/openapi";
+
+const root = fileURLToPath(new URL("..", import.meta.url));
+
+// `defineRouteMeta` is extracted at build time, so the operations are read from the route files,
+// keyed like the spec, e.g. `/api/v1/mosaic/[username]/[repositoryName]` and `get`.
+function getRouteOperations(): Record<string, Record<string, { tags?: string[] }>> {
+  const paths: Record<string, Record<string, { tags?: string[] }>> = {};
+
+  for (const dir of ["api", "routes"]) {
+    for (const file of readdirSync(join(root, dir), { recursive: true, encoding: "utf8" })) {
+      if (!file.endsWith(".ts")) {
+        continue;
+      }
+
+      const source = readFileSync(join(root, dir, file), "utf8");
+      const start = source.indexOf("defineRouteMeta(");
+
+      if (start === -1) {
+        continue;
+      }
+
+      const tags = source.slice(start).match(/\btags:\s*\[([^\]]*)\]/)?.[1];
+      const [, path, method = "get"] = `/${dir}/${file}`.replaceAll("\\", "/").match(/^(.+?)(?:\.(get|post|put|patch|delete))?\.ts$/)!;
+
+      paths[path] ??= {};
+      paths[path][method] = {
+        tags: tags != null ? [...tags.matchAll(/["']([^"']+)["']/g)].map((match) => match[1]) : undefined,
+      };
+    }
+  }
+
+  return paths;
+}
+
+describe("route metas", () => {
+  const operations = getRouteOperations();
+
+  it("finds the route metas", () => {
+    expect(operations["/api/openapi.json"]?.get).toEqual({ tags: ["meta"] });
+  });
+
+  it("only uses tags registered in OPENAPI_TAGS", () => {
+    expect(findUnregisteredTags(operations)).toEqual([]);
+  });
+});
+
+describe("findUnregisteredTags", () => {
+  it("lists unknown and missing tags", () => {
+    expect(findUnregisteredTags({
+      "/api/v1/foo": { get: { tags: ["foo", "projects"] }, post: {} },
+      "/api/v1/bar": { get: { tags: ["projects"] } },
+    })).toEqual(["GET /api/v1/foo (foo)", "POST /api/v1/foo (untagged)"]);
+  });
+});
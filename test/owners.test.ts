@@ -0,0 +1,70 @@
+import type { OwnerRepository } from "../utils/owners";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { mapWithConcurrency } from "../utils/concurrency";
+import { GitHubError } from "../utils/github";
+import { evaluateSyncRules, settleRepositories } from "../utils/owners";
+
+describe("evaluateSyncRules", () => {
+  const rules = ["luxass/*", "!luxass/dotfiles*", "!*-archive"];
+
+  it("includes every repository without rules", () => {
+    expect(evaluateSyncRules([], "luxass/mosaic")).toEqual({ included: true });
+  });
+
+  it("lets the last matching rule win", () => {
+    expect(evaluateSyncRules(rules, "luxass/mosaic")).toEqual({ included: true, rule: "luxass/*" });
+    expect(evaluateSyncRules(rules, "luxass/dotfiles-old")).toEqual({ included: false, rule: "!luxass/dotfiles*" });
+    expect(evaluateSyncRules(["!luxass/*", "luxass/mosaic"], "luxass/mosaic")).toEqual({ included: true, rule: "luxass/mosaic" });
+  });
+
+  it("matches patterns without an owner against the repository name", () => {
+    expect(evaluateSyncRules(rules, "luxass/mosaic-archive")).toEqual({ included: false, rule: "!*-archive" });
+    expect(evaluateSyncRules(["!*-archive"], "other/mosaic-archive")).toEqual({ included: false, rule: "!*-archive" });
+  });
+
+  it("requires an include rule unless every rule is an exclusion", () => {
+    expect(evaluateSyncRules(rules, "other/mosaic")).toEqual({ included: false });
+    expect(evaluateSyncRules(["!*-archive"], "other/mosaic")).toEqual({ included: true });
+  });
+
+  it("ignores case", () => {
+    expect(evaluateSyncRules(["Luxass/Mosaic"], "luxass/mosaic")).toEqual({ included: true, rule: "Luxass/Mosaic" });
+  });
+});
+
+describe("settleRepositories", () => {
+  const repositories = ["luxass/a", "luxass/b", "luxass/c"].map((nameWithOwner) => ({ nameWithOwner }) as OwnerRepository);
+
+  beforeEach(() => {
+    vi.stubGlobal("mapWithConcurrency", mapWithConcurrency);
+    vi.stubGlobal("GitHubError", GitHubError);
+    vi.spyOn(console, "warn").mockImplementation(() => {});
+  });
+
+  it("skips repositories github refuses", async () => {
+    const settled = await settleRepositories(repositories, 2, async (repository) => {
+      if (repository.nameWithOwner === "luxass/b") {
+        throw new GitHubError("rate_limited", "rate limit exceeded", { url: "https://api.github.com/graphql" });
+      }
+
+      return repository.nameWithOwner;
+    });
+
+    expect(settled).toEqual({
+      results: ["luxass/a", "luxass/c"],
+      skipped: [{ nameWithOwner: "luxass/b", reason: "rate limit exceeded" }],
+    });
+  });
+
+  it("leaves out `undefined` results", async () => {
+    const settled = await settleRepositories(repositories, 2, async (repository) => repository.nameWithOwner === "luxass/a" ? undefined : 1);
+
+    expect(settled).toEqual({ results: [1, 1], skipped: [] });
+  });
+
+  it("rethrows other errors", async () => {
+    await expect(settleRepositories(repositories, 2, async () => {
+      throw new Error("unexpected");
+    })).rejects.toThrow("unexpected");
+  });
+});
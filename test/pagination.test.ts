@@ -0,0 +1,44 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { paginateStorage } from "../utils/pagination";
+
+interface Item {
+  id: number;
+}
+
+beforeEach(() => {
+  const items = new Map<string, Item>(
+    [1, 2, 3, 4, 5].map((id) => [`inbox:0${id}`, { id }]),
+  );
+
+  vi.stubGlobal("useStorage", () => ({
+    getKeys: async (prefix: string) => [...items.keys()].filter((key) => key.startsWith(prefix)),
+    getItem: async (key: string) => items.get(key) ?? null,
+  }));
+});
+
+describe("paginateStorage", () => {
+  it("loads pages in reverse key order", async () => {
+    const first = await paginateStorage<Item>("inbox", { limit: 2 });
+    expect(first).toEqual({ items: [{ id: 5 }, { id: 4 }], nextCursor: "inbox:04" });
+
+    const second = await paginateStorage<Item>("inbox", { limit: 2, cursor: first.nextCursor! });
+    expect(second).toEqual({ items: [{ id: 3 }, { id: 2 }], nextCursor: "inbox:02" });
+
+    const last = await paginateStorage<Item>("inbox", { limit: 2, cursor: second.nextCursor! });
+    expect(last).toEqual({ items: [{ id: 1 }], nextCursor: null });
+  });
+
+  it("keeps loading until the page is full when filtering", async () => {
+    const isOdd = (item: Item) => item.id % 2 === 1;
+
+    const first = await paginateStorage<Item>("inbox", { limit: 2 }, isOdd);
+    expect(first).toEqual({ items: [{ id: 5 }, { id: 3 }], nextCursor: "inbox:03" });
+
+    const last = await paginateStorage<Item>("inbox", { limit: 2, cursor: first.nextCursor! }, isOdd);
+    expect(last).toEqual({ items: [{ id: 1 }], nextCursor: null });
+  });
+
+  it("returns an empty page for an unknown prefix", async () => {
+    expect(await paginateStorage<Item>("views", { limit: 2 })).toEqual({ items: [], nextCursor: null });
+  });
+});
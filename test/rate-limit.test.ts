@@ -0,0 +1,51 @@
+import type { RateLimitGroup } from "../utils/rate-limit";
+import { describe, expect, it } from "vitest";
+import { TokenBucketLimiter } from "../utils/rate-limit";
+
+const group: RateLimitGroup = {
+  name: "default",
+  pattern: "/api/**",
+  capacity: 2,
+  refillPerMinute: 60,
+};
+
+describe("TokenBucketLimiter.take", () => {
+  it("allows bursts up to the capacity", () => {
+    const limiter = new TokenBucketLimiter();
+
+    expect(limiter.take("client", group, 0)).toEqual({ allowed: true, limit: 2, remaining: 1, reset: 1, retryAfter: undefined });
+    expect(limiter.take("client", group, 0)).toEqual({ allowed: true, limit: 2, remaining: 0, reset: 2, retryAfter: undefined });
+    expect(limiter.take("client", group, 0)).toEqual({ allowed: false, limit: 2, remaining: 0, reset: 2, retryAfter: 1 });
+  });
+
+  it("refills the bucket over time", () => {
+    const limiter = new TokenBucketLimiter();
+
+    limiter.take("client", group, 0);
+    limiter.take("client", group, 0);
+
+    expect(limiter.take("client", group, 500).allowed).toBe(false);
+    expect(limiter.take("client", group, 1_000).allowed).toBe(true);
+    expect(limiter.take("client", group, 60_000).remaining).toBe(1);
+  });
+
+  it("keeps a bucket per key", () => {
+    const limiter = new TokenBucketLimiter();
+
+    limiter.take("a", group, 0);
+    limiter.take("a", group, 0);
+
+    expect(limiter.take("a", group, 0).allowed).toBe(false);
+    expect(limiter.take("b", group, 0).allowed).toBe(true);
+  });
+
+  it("evicts the least recently used buckets", () => {
+    const limiter = new TokenBucketLimiter({ maxBuckets: 1 });
+
+    limiter.take("a", group, 0);
+    limiter.take("b", group, 0);
+
+    // `a` was evicted, so it starts out full again.
+    expect(limiter.take("a", group, 0).remaining).toBe(1);
+  });
+});
@@ -0,0 +1,36 @@
+import { describe, expect, it } from "vitest";
+import { getNextPageUrl } from "../utils/github";
+import { compareSemverTags } from "../utils/releases";
+
+describe("getNextPageUrl", () => {
+  it("returns the url of the next page", () => {
+    const link = "<https://api.github.com/repos/luxass/mosaic/releases?page=2>; rel=\"next\", <https://api.github.com/repos/luxass/mosaic/releases?page=5>; rel=\"last\"";
+    expect(getNextPageUrl(link)).toBe("https://api.github.com/repos/luxass/mosaic/releases?page=2");
+  });
+
+  it("returns `undefined` on the last page", () => {
+    const link = "<https://api.github.com/repos/luxass/mosaic/releases?page=4>; rel=\"prev\", <https://api.github.com/repos/luxass/mosaic/releases?page=1>; rel=\"first\"";
+    expect(getNextPageUrl(link)).toBeUndefined();
+    expect(getNextPageUrl(null)).toBeUndefined();
+  });
+});
+
+describe("compareSemverTags", () => {
+  it("sorts the newest version first", () => {
+    expect(["v1.0.0", "v1.10.0", "v1.2.0", "v0.9.9"].sort(compareSemverTags)).toEqual(["v1.10.0", "v1.2.0", "v1.0.0", "v0.9.9"]);
+  });
+
+  it("sorts a release before its prereleases", () => {
+    expect(["1.0.0-beta.2", "1.0.0", "1.0.0-beta.10"].sort(compareSemverTags)).toEqual(["1.0.0", "1.0.0-beta.10", "1.0.0-beta.2"]);
+  });
+
+  it("allows a `v` or package name prefix and build metadata", () => {
+    expect(compareSemverTags("pkg@2.0.0", "v1.0.0")).toBeLessThan(0);
+    expect(compareSemverTags("v1.0.0", "1.0.0")).toBe(0);
+    expect(compareSemverTags("v1.0.0+build.1", "v1.0.0")).toBe(0);
+  });
+
+  it("sorts tags that are not semver last", () => {
+    expect(["latest", "v1.0.0", "nightly"].sort(compareSemverTags)).toEqual(["v1.0.0", "latest", "nightly"]);
+  });
+});
@@ -0,0 +1,53 @@
+import { describe, expect, it } from "vitest";
+import { diffJson, isMirroredResponse } from "../utils/shadow";
+
+describe("diffJson", () => {
+  it("returns nothing for equal values", () => {
+    expect(diffJson({ a: 1, b: [1, { c: "d" }] }, { a: 1, b: [1, { c: "d" }] })).toEqual([]);
+  });
+
+  it("lists the paths of differing values", () => {
+    expect(diffJson({ a: 1, b: [1, 2] }, { a: 2, b: [1, 3] })).toEqual(["$.a", "$.b[1]"]);
+    expect(diffJson({ a: { b: 1 } }, { a: null })).toEqual(["$.a"]);
+  });
+
+  it("lists missing keys and differing lengths", () => {
+    expect(diffJson({ a: 1 }, {})).toEqual(["$.a"]);
+    expect(diffJson({}, { a: 1 })).toEqual(["$.a"]);
+    expect(diffJson([1, 2], [1])).toEqual(["$.length"]);
+  });
+
+  it("ignores volatile keys", () => {
+    expect(diffJson({ timestamp: 1, a: { checkedAt: 1 } }, { timestamp: 2, a: { checkedAt: 2 } })).toEqual([]);
+  });
+
+  it("returns at most 50 paths", () => {
+    const a = Array.from({ length: 100 }, (_, i) => i);
+    const b = a.map((value) => value + 1);
+
+    expect(diffJson(a, b)).toHaveLength(50);
+  });
+});
+
+describe("isMirroredResponse", () => {
+  it("mirrors JSON strings", () => {
+    expect(isMirroredResponse("application/json; charset=utf-8", "{}")).toBe(true);
+    expect(isMirroredResponse("application/problem+json", "{}")).toBe(true);
+    expect(isMirroredResponse("text/csv", "a,b")).toBe(false);
+    expect(isMirroredResponse(undefined, "<html></html>")).toBe(false);
+  });
+
+  it("mirrors plain objects and arrays", () => {
+    expect(isMirroredResponse(undefined, { a: 1 })).toBe(true);
+    expect(isMirroredResponse("application/json", [1, 2])).toBe(true);
+    expect(isMirroredResponse("text/plain", { a: 1 })).toBe(false);
+  });
+
+  it("skips streams and binary bodies", () => {
+    expect(isMirroredResponse("application/x-ndjson", new ReadableStream())).toBe(false);
+    expect(isMirroredResponse("application/json", new ReadableStream())).toBe(false);
+    expect(isMirroredResponse("image/png", new Uint8Array([1]))).toBe(false);
+    expect(isMirroredResponse(undefined, new Response("{}"))).toBe(false);
+    expect(isMirroredResponse(undefined, null)).toBe(false);
+  });
+});
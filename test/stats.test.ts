@@ -0,0 +1,22 @@
+import { describe, expect, it } from "vitest";
+import { parseStatsRange } from "../utils/stats";
+
+describe("parseStatsRange", () => {
+  it("parses days, weeks and months", () => {
+    expect(parseStatsRange("30d")).toBe(30);
+    expect(parseStatsRange("2w")).toBe(14);
+    expect(parseStatsRange("3m")).toBe(90);
+  });
+
+  it("rejects empty and unknown ranges", () => {
+    expect(parseStatsRange("0d")).toBeUndefined();
+    expect(parseStatsRange("1y")).toBeUndefined();
+    expect(parseStatsRange("d")).toBeUndefined();
+    expect(parseStatsRange("-1d")).toBeUndefined();
+  });
+
+  it("rejects ranges longer than the stored snapshots", () => {
+    expect(parseStatsRange("366d")).toBe(366);
+    expect(parseStatsRange("13m")).toBeUndefined();
+  });
+});
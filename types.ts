@@ -6,21 +6,45 @@ import type {
   PROJECT_SCHEMA,
   WEBSITE_SCHEMA,
 } from "./utils/json-schema";
+import type { FundingLink } from "./utils/funding";
 
 type SafeOmit<T, K extends keyof T> = Omit<T, K>;
 
 export type ResolvedProject = {
   name: string;
-  website?: SafeOmit<z.infer<typeof WEBSITE_SCHEMA>, "enabled">;
+  /**
+   * The user or organization owning the repository.
+   */
+  owner?: string;
+  website?: SafeOmit<z.infer<typeof WEBSITE_SCHEMA>, "enabled" | "title" | "description"> & {
+    title?: string;
+    description?: string;
+    deployment?: {
+      state?: string;
+      url?: string;
+      /**
+       * When the latest production deployment was created, missing if the project was never deployed.
+       */
+      createdAt?: string;
+      broken: boolean;
+    };
+  };
   npm?: SafeOmit<z.infer<typeof NPM_SCHEMA>, "enabled" | "downloads"> & {
     url?: string;
     downloads?: number;
   };
   deprecated?: z.infer<typeof DEPRECATED_SCHEMA>;
   readme?: string;
-} & SafeOmit<z.infer<typeof PROJECT_SCHEMA>, "version" | "stars"> & {
+  funding?: FundingLink[];
+} & SafeOmit<z.infer<typeof PROJECT_SCHEMA>, "version" | "stars" | "downloads" | "description"> & {
+  description?: string;
+  /**
+   * The locales the localized fields of the project are available in.
+   */
+  locales?: string[];
   version?: string;
   stars?: number;
+  downloads?: number;
 };
 
 export type Project = ResolvedProject &
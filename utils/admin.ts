@@ -0,0 +1,20 @@
+import type { H3Event } from "h3";
+
+/**
+ * Ensures the request is authorized with the admin token.
+ *
+ * @param {H3Event} event - The incoming request.
+ * @throws {H3Error} A `401` error if the token is missing or wrong.
+ */
+export function requireAdmin(event: H3Event): void {
+  const runtimeConfig = useRuntimeConfig();
+  const authorization = getRequestHeader(event, "authorization");
+
+  if (!runtimeConfig.adminToken || authorization !== `Bearer ${runtimeConfig.adminToken}`) {
+    throw createApiError({
+      status: 401,
+      code: "unauthorized",
+      message: "missing or invalid admin token",
+    });
+  }
+}
@@ -0,0 +1,85 @@
+export interface BadgeOptions {
+  label: string;
+  message: string;
+  /**
+   * The background color of the message, e.g. `#4c1`.
+   */
+  color?: string;
+}
+
+function escapeXml(value: string): string {
+  return value
+    .replace(/&/g, "&amp;")
+    .replace(/</g, "&lt;")
+    .replace(/>/g, "&gt;")
+    .replace(/"/g, "&quot;")
+    .replace(/'/g, "&apos;");
+}
+
+// rough width of 11px Verdana, which is what shields uses. good enough without measuring fonts.
+function textWidth(text: string): number {
+  let width = 0;
+  for (const char of text) {
+    if ("ijlt.,:;|!'".includes(char)) {
+      width += 3.5;
+    } else if ("mwMW@".includes(char)) {
+      width += 10;
+    } else if (char === char.toUpperCase() && char !== char.toLowerCase()) {
+      width += 7.5;
+    } else {
+      width += 6.5;
+    }
+  }
+
+  return Math.ceil(width);
+}
+
+/**
+ * Renders a flat, shields-style badge.
+ *
+ * @param {BadgeOptions} options - The label, message and color of the badge.
+ * @returns {string} The badge as an SVG document.
+ */
+export function renderBadge(options: BadgeOptions): string {
+  const label = escapeXml(options.label);
+  const message = escapeXml(options.message);
+  const color = escapeXml(options.color || "#007ec6");
+
+  const labelWidth = textWidth(options.label) + 10;
+  const messageWidth = textWidth(options.message) + 10;
+  const width = labelWidth + messageWidth;
+
+  return `<svg xmlns="http://www.w3.org/2000/svg" width="${width}" height="20" role="img" aria-label="${label}: ${message}">
+  <title>${label}: ${message}</title>
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <clipPath id="r">
+    <rect width="${width}" height="20" rx="3" fill="#fff"/>
+  </clipPath>
+  <g clip-path="url(#r)">
+    <rect width="${labelWidth}" height="20" fill="#555"/>
+    <rect x="${labelWidth}" width="${messageWidth}" height="20" fill="${color}"/>
+    <rect width="${width}" height="20" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="${labelWidth / 2}" y="15" fill="#010101" fill-opacity=".3">${label}</text>
+    <text x="${labelWidth / 2}" y="14">${label}</text>
+    <text x="${labelWidth + messageWidth / 2}" y="15" fill="#010101" fill-opacity=".3">${message}</text>
+    <text x="${labelWidth + messageWidth / 2}" y="14">${message}</text>
+  </g>
+</svg>`;
+}
+
+export function formatCount(count: number): string {
+  if (count >= 1_000_000) {
+    return `${(count / 1_000_000).toFixed(1).replace(/\.0$/, "")}M`;
+  }
+
+  if (count >= 1_000) {
+    return `${(count / 1_000).toFixed(1).replace(/\.0$/, "")}k`;
+  }
+
+  return String(count);
+}
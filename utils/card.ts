@@ -0,0 +1,55 @@
+export interface ProjectCard {
+  name: string;
+  nameWithOwner: string;
+  description?: string;
+  url: string;
+  website?: string;
+  stars: number;
+  languages: { name: string; color: string }[];
+  ogImage: string;
+}
+
+/**
+ * Builds the embeddable card of a project.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @param {string[]} [locales] - The preferred locales of the description, as returned by `getRequestLocales`.
+ * @returns {Promise<ProjectCard | undefined>} A promise that resolves to the card, or `undefined` if the repository could not be found,
+ * is private, or has no resolved config or is ignored.
+ */
+export async function getProjectCard(
+  owner: string,
+  repository: string,
+  locales: string[] = [],
+): Promise<ProjectCard | undefined> {
+  const [resolvedMosaicConfig, repo] = await Promise.all([
+    resolveMosaicConfig(owner, repository),
+    getRepository(owner, repository),
+  ]);
+
+  // only projects shown by mosaic get a card, like the views and link checks.
+  if (
+    !repo
+    || repo.isPrivate
+    || resolvedMosaicConfig.type !== "resolved"
+    || resolvedMosaicConfig.content.project.ignore
+  ) {
+    return undefined;
+  }
+
+  const config = resolvedMosaicConfig.content;
+
+  return {
+    name: config.project.name || repo.name,
+    nameWithOwner: repo.nameWithOwner,
+    description: localize(config.project.description, locales) || repo.description || undefined,
+    url: repo.url,
+    website: (config.website?.enabled && config.website.url) || repo.homepageUrl || undefined,
+    stars: repo.stargazerCount,
+    languages: (repo.languages?.nodes || []).flatMap((language) => language
+      ? [{ name: language.name, color: language.color || "#000000" }]
+      : []),
+    ogImage: repo.openGraphImageUrl,
+  };
+}
@@ -0,0 +1,27 @@
+/**
+ * Maps over a list with at most `limit` promises running at the same time.
+ *
+ * @param {T[]} items - The items to map over.
+ * @param {number} limit - The maximum number of concurrent calls to `fn`.
+ * @param {(item: T, index: number) => Promise<R>} fn - The mapper.
+ * @returns {Promise<R[]>} The results in the same order as `items`.
+ */
+export async function mapWithConcurrency<T, R>(
+  items: T[],
+  limit: number,
+  fn: (item: T, index: number) => Promise<R>,
+): Promise<R[]> {
+  const results: R[] = Array.from({ length: items.length });
+  let next = 0;
+
+  async function worker() {
+    while (next < items.length) {
+      const index = next++;
+      results[index] = await fn(items[index], index);
+    }
+  }
+
+  await Promise.all(Array.from({ length: Math.min(limit, items.length) }, worker));
+
+  return results;
+}
@@ -1,24 +1,101 @@
-import type { z } from "zod";
-import { parse as parseToml } from "smol-toml";
-import { zodErrorMap } from "zod-error-utils";
-
-type ResolvedConfig = z.infer<typeof MOSAIC_SCHEMA>;
+import type { MosaicConfig } from "~/core/config";
+import { parseMosaicConfig } from "~/core/config";
+import type { ConfigWarning } from "~/core/migrations";
+import type { Page, PageOptions } from "./pagination";
 
 type ConfigResult =
   | {
     type: "resolved";
-    content: ResolvedConfig;
+    content: MosaicConfig;
     external: boolean;
     path: string;
+    warnings: ConfigWarning[];
   }
   | { type: "error"; message: string; details?: unknown }
   | { type: "not_found" };
 
-export interface ResolveConfigOptions {
+type ResolvedConfigResult = Extract<ConfigResult, { type: "resolved" }>;
+
+interface ConfigSource {
+  content: string;
+  external: boolean;
+  path: string;
+}
+
+export interface StoredConfigWarnings {
   owner: string;
-  name: string;
+  repository: string;
+  path: string;
+  updatedAt: string;
+  warnings: ConfigWarning[];
+}
+
+const CONFIG_WARNINGS_PREFIX = "config-warnings";
+
+const STAGE_TIMEOUTS = {
+  fetch: 10_000,
+  parse: 2_000,
+} as const;
+
+/**
+ * Fetches the raw `mosaic.toml` of a repository from GitHub.
+ */
+async function fetchConfigSource(
+  owner: string,
+  repository: string,
+  signal: AbortSignal,
+): Promise<ConfigSource | undefined> {
+  let external = false;
+
+  let url = new URL(
+    `https://api.github.com/repos/${owner}/${repository}/contents/.github/mosaic.toml`,
+  );
+
+  if (!isConfiguredOwner(owner)) {
+    external = true;
+
+    // when the owner is not luxass or one of the configured owners, resolve the repository externally
+    // every external repository that should be resolved, requires
+    // a `mosaic.toml` file in luxass/luxass repository
+    // the path for these files should be .github/mosaic/<external-owner>/<external-repository>.toml
+    // for example: .github/mosaic/vercel/next.js.toml
+    if (repository.endsWith(".toml")) {
+      repository = repository.slice(0, -5);
+    }
+
+    url = new URL(
+      `https://api.github.com/repos/luxass/luxass/contents/.github/mosaic/${owner.toLowerCase()}/${repository.toLowerCase()}.toml`,
+    );
+  }
+
+  // external configs live in luxass/luxass, so they are read with the default token.
+  const result = await githubFetchOptional(url.toString(), { signal, token: external ? undefined : getOwnerToken(owner) });
+
+  if (
+    !result
+    || typeof result !== "object"
+    || !("content" in result)
+    || typeof result.content !== "string"
+  ) {
+    // eslint-disable-next-line no-console
+    console.info("repository has no config defined or invalid response from github", result);
+    return undefined;
+  }
+
+  return {
+    content: base64ToString(result.content),
+    external,
+    path: `https://github.com/${owner}/${repository}/blob/main/.github/mosaic.toml`,
+  };
 }
 
+/**
+ * Resolves the `mosaic.toml` of a repository in two bounded stages:
+ * fetching the file from GitHub, then parsing and validating it.
+ *
+ * Resolution is not tied to the request that started it, most callers are cached handlers
+ * whose result is shared with every request waiting on it, so the stages are only bounded by their timeouts.
+ */
 export async function resolveMosaicConfig(
   owner: string,
   repository: string,
@@ -27,87 +104,57 @@ export async function resolveMosaicConfig(
     return { type: "not_found" };
   }
 
-  try {
-    let external = false;
+  const attributes = { "github.repository": `${owner}/${repository}` };
 
-    let url = new URL(
-      `https://api.github.com/repos/${owner}/${repository}/contents/.github/mosaic.toml`,
+  try {
+    const source = await runStage(
+      "config.fetch",
+      { timeout: STAGE_TIMEOUTS.fetch, attributes },
+      (signal) => fetchConfigSource(owner, repository, signal),
     );
 
-    if (owner !== "luxass") {
-      external = true;
-
-      // when the owner is not luxass, resolve the repository externally
-      // every external repository that should be resolved, requires
-      // a `mosaic.toml` file in luxass/luxass repository
-      // the path for these files should be .github/mosaic/<external-owner>/<external-repository>.toml
-      // for example: .github/mosaic/vercel/next.js.toml
-      if (repository.endsWith(".toml")) {
-        repository = repository.slice(0, -5);
-      }
-
-      url = new URL(
-        `https://api.github.com/repos/luxass/luxass/contents/.github/mosaic/${owner.toLowerCase()}/${repository.toLowerCase()}.toml`,
-      );
-    }
-
-    const runtimeConfig = useRuntimeConfig();
-
-    const result = await fetch(url, {
-      headers: {
-        "Authorization": `Bearer ${runtimeConfig.github.token}`,
-        "Content-Type": "application/vnd.github+json",
-        "X-GitHub-Api-Version": "2022-11-28",
-      },
-    }).then((res) => res.json());
-
-    if (
-      !result
-      || typeof result !== "object"
-      || !("content" in result)
-      || typeof result.content !== "string"
-    ) {
-      // eslint-disable-next-line no-console
-      console.info("repository has no config defined or invalid response from github", result);
+    if (!source) {
       return { type: "not_found" };
     }
 
-    const content = parseToml(base64ToString(result.content));
-
-    const parsed = await MOSAIC_SCHEMA.safeParseAsync(content, {
-      errorMap: zodErrorMap,
-    });
+    const parsed = await runStage(
+      "config.parse",
+      { timeout: STAGE_TIMEOUTS.parse, attributes },
+      () => parseMosaicConfig(source.content),
+    );
 
     if (!parsed.success) {
-      console.error(parsed.error);
+      console.error(parsed.details);
       return {
         type: "error",
-        message: "error resolving config due to config not being valid",
-        details: parsed.error,
+        message: parsed.message,
+        details: parsed.details,
       };
     }
 
-    const config = parsed.data;
-    if (config.workspace?.enabled && config.workspace.overrides != null) {
-      for (const [key] of Object.entries(config.workspace.overrides)) {
-        const projectOverride = config.workspace.overrides[key];
-        if (projectOverride == null) {
-          throw new Error("project not found, how did this happen?");
-        }
-
-        projectOverride.project.name = key;
-      }
-    }
-
     return {
       type: "resolved",
-      content: config,
-      external,
-      path: `https://github.com/${owner}/${repository}/blob/main/.github/mosaic.toml`,
+      content: parsed.data,
+      external: source.external,
+      path: source.path,
+      warnings: parsed.warnings,
     };
   } catch (err) {
+    // github errors are surfaced by the error handler, so an expired token isn't reported as an invalid config.
+    if (err instanceof GitHubError) {
+      throw err;
+    }
+
     console.error(err);
 
+    if (err instanceof StageTimeoutError) {
+      return {
+        type: "error",
+        message: `error resolving config due to ${err.message}`,
+        details: { stage: err.stage, timeout: err.timeout },
+      };
+    }
+
     return {
       type: "error",
       message: "error resolving config due to an internal error",
@@ -115,3 +162,99 @@ export async function resolveMosaicConfig(
     };
   }
 }
+
+/**
+ * Resolves the `mosaic.toml` of a repository for a route that can't respond without it.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<ResolvedConfigResult>} A promise that resolves to the resolved config.
+ * @throws {H3Error} A `config_not_found` error if the repository has no config, or `config_invalid` if the config is not valid.
+ */
+export async function requireResolvedConfig(owner: string, repository: string): Promise<ResolvedConfigResult> {
+  const resolvedMosaicConfig = await resolveMosaicConfig(owner, repository);
+
+  if (resolvedMosaicConfig.type === "not_found") {
+    throw createApiError({
+      status: 404,
+      code: "config_not_found",
+      message: "repository has no config defined",
+    });
+  }
+
+  if (resolvedMosaicConfig.type === "error") {
+    throw createApiError({
+      status: 400,
+      code: "config_invalid",
+      message: "error resolving config due to config not being valid",
+      data: resolvedMosaicConfig.details,
+    });
+  }
+
+  return resolvedMosaicConfig;
+}
+
+/**
+ * Stores the deprecation warnings of a config, so they can be listed by the admin api.
+ * Storage is only written when the warnings changed since they were last stored.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @param {string} path - The path of the config.
+ * @param {ConfigWarning[]} warnings - The warnings of the resolved config.
+ * @returns {Promise<void>}
+ */
+export async function storeConfigWarnings(
+  owner: string,
+  repository: string,
+  path: string,
+  warnings: ConfigWarning[],
+): Promise<void> {
+  const storage = useStorage("mosaic");
+  const key = `${CONFIG_WARNINGS_PREFIX}:${owner}:${repository}`;
+  const stored = await storage.getItem<StoredConfigWarnings>(key);
+
+  if (!warnings.length) {
+    if (stored) {
+      await storage.removeItem(key);
+    }
+
+    return;
+  }
+
+  if (stored && stored.path === path && JSON.stringify(stored.warnings) === JSON.stringify(warnings)) {
+    return;
+  }
+
+  await storage.setItem(key, {
+    owner,
+    repository,
+    path,
+    updatedAt: new Date().toISOString(),
+    warnings,
+  } satisfies StoredConfigWarnings);
+}
+
+/**
+ * Retrieves the stored deprecation warnings of a config.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<StoredConfigWarnings | null>} A promise that resolves to the stored warnings, or `null` if the config has none.
+ */
+export async function getProjectConfigWarnings(
+  owner: string,
+  repository: string,
+): Promise<StoredConfigWarnings | null> {
+  return await useStorage("mosaic").getItem<StoredConfigWarnings>(`${CONFIG_WARNINGS_PREFIX}:${owner}:${repository}`);
+}
+
+/**
+ * Lists a page of the deprecation warnings of configs that used deprecated keys the last time they were synced.
+ *
+ * @param {PageOptions} page - The page to load.
+ * @returns {Promise<Page<StoredConfigWarnings>>} A promise that resolves to the page of stored warnings.
+ */
+export async function getConfigWarningsPage(page: PageOptions): Promise<Page<StoredConfigWarnings>> {
+  return await paginateStorage<StoredConfigWarnings>(CONFIG_WARNINGS_PREFIX, page);
+}
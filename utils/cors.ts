@@ -0,0 +1,38 @@
+export interface CorsGroup {
+  /**
+   * A glob matched against the request path.
+   */
+  pattern: string;
+  methods: string[];
+  /**
+   * Overrides the globally allowed origins for this group.
+   */
+  origins?: string[];
+}
+
+/**
+ * Checks an origin against an allowlist. Entries can contain a `*` wildcard
+ * for subdomains, e.g. `https://*.vercel.app`, or be `*` to allow every origin.
+ *
+ * @param {string} origin - The value of the `Origin` header.
+ * @param {string[]} allowlist - The allowed origins.
+ * @returns {boolean} `true` if the origin is allowed.
+ */
+export function isAllowedOrigin(origin: string, allowlist: string[]): boolean {
+  return allowlist.some((allowed) => {
+    if (allowed === "*" || allowed === origin) {
+      return true;
+    }
+
+    if (!allowed.includes("*")) {
+      return false;
+    }
+
+    const pattern = allowed
+      .split("*")
+      .map((part) => part.replace(/[.+?^${}()|[\]\\]/g, "\\$&"))
+      .join("[a-z0-9-]+(?:\\.[a-z0-9-]+)*");
+
+    return new RegExp(`^${pattern}$`, "i").test(origin);
+  });
+}
@@ -0,0 +1,50 @@
+import type { z } from "zod";
+import type { README_SCHEMA } from "./json-schema";
+
+export interface DocPage {
+  slug: string;
+  path: string;
+  title?: string;
+}
+
+function slugify(path: string): string {
+  const name = path.split("/").pop() || path;
+
+  return name
+    .replace(/\.mdx?$/i, "")
+    .toLowerCase()
+    .replace(/[^a-z0-9]+/g, "-")
+    .replace(/^-|-$/g, "");
+}
+
+/**
+ * Normalizes the `docs` of a readme config into pages with unique slugs.
+ *
+ * @param {z.infer<typeof README_SCHEMA> | undefined} readme - The readme config.
+ * @returns {DocPage[]} The documentation pages, in the order they are configured.
+ */
+export function getDocPages(readme: z.infer<typeof README_SCHEMA> | undefined): DocPage[] {
+  const pages: DocPage[] = [];
+  const slugs = new Set<string>();
+
+  for (const doc of readme?.docs || []) {
+    const page: DocPage = typeof doc === "string"
+      ? { slug: slugify(doc), path: doc }
+      : { slug: doc.slug || slugify(doc.path), path: doc.path, title: doc.title };
+
+    // two files with the same name in different directories, e.g. `a/intro.md` and `b/intro.md`
+    let slug = page.slug;
+    for (let i = 2; slugs.has(slug); i++) {
+      slug = `${page.slug}-${i}`;
+    }
+
+    slugs.add(slug);
+    pages.push({ ...page, slug });
+  }
+
+  return pages;
+}
+
+export function inferTitle(markdown: string): string | undefined {
+  return markdown.match(/^#\s+(.+)$/m)?.[1]?.trim();
+}
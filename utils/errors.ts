@@ -0,0 +1,83 @@
+import type { H3Error } from "h3";
+
+/**
+ * Stable, machine-readable error codes, returned as `code` in every error response.
+ */
+export const API_ERROR_CODES = [
+  "bad_request",
+  "unauthorized",
+  "missing_params",
+  "invalid_request",
+  "not_found",
+  "project_not_found",
+  "project_ignored",
+  "config_not_found",
+  "config_invalid",
+  "readme_not_found",
+  "readme_error",
+  "package_not_found",
+  "deployment_not_found",
+  "rate_limited",
+  "captcha_failed",
+  "github_rate_limited",
+  "github_abuse_detected",
+  "github_not_found",
+  "github_unauthorized",
+  "github_graphql_error",
+  "upstream_error",
+  "vercel_unavailable",
+  "not_implemented",
+  "internal_error",
+] as const;
+
+export type ApiErrorCode = typeof API_ERROR_CODES[number];
+
+export interface ApiErrorOptions {
+  status: number;
+  code: ApiErrorCode;
+  message: string;
+  data?: unknown;
+}
+
+export interface ApiErrorData {
+  code: ApiErrorCode;
+  details?: unknown;
+}
+
+/**
+ * Creates an error that is rendered as a `application/problem+json` response by the error handler.
+ *
+ * @param {ApiErrorOptions} options - The status, code and message of the error.
+ * @returns {H3Error<ApiErrorData>} The error, ready to be thrown.
+ */
+export function createApiError(options: ApiErrorOptions): H3Error<ApiErrorData> {
+  return createError<ApiErrorData>({
+    status: options.status,
+    message: options.message,
+    data: {
+      code: options.code,
+      details: options.data,
+    },
+  });
+}
+
+export function defaultErrorCode(status: number): ApiErrorCode {
+  switch (status) {
+    case 400:
+      return "bad_request";
+    case 401:
+      return "unauthorized";
+    case 404:
+      return "not_found";
+    case 429:
+      return "rate_limited";
+    case 501:
+      return "not_implemented";
+    case 502:
+    case 503:
+    case 504:
+      return "upstream_error";
+    default:
+      return "internal_error";
+  }
+}
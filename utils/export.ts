@@ -0,0 +1,208 @@
+import type { H3Event } from "h3";
+
+export type ExportFormat = "json" | "csv" | "ndjson";
+
+const EXPORT_CONTENT_TYPES: Record<Exclude<ExportFormat, "json">, string> = {
+  csv: "text/csv; charset=utf-8",
+  ndjson: "application/x-ndjson",
+};
+
+/**
+ * Picks the format of a list response from `?format=`, falling back to the `Accept` header.
+ *
+ * @param {H3Event} event - The request event.
+ * @returns {ExportFormat | undefined} The format, or `undefined` if `?format=` is not supported.
+ */
+export function negotiateExportFormat(event: H3Event): ExportFormat | undefined {
+  const { format } = getQuery(event);
+
+  if (typeof format === "string") {
+    return (["json", "csv", "ndjson"] as const).find((value) => value === format);
+  }
+
+  const accept = getRequestHeader(event, "accept") || "";
+
+  if (accept.includes("text/csv")) {
+    return "csv";
+  }
+
+  if (accept.includes("application/x-ndjson")) {
+    return "ndjson";
+  }
+
+  return "json";
+}
+
+/**
+ * Reads a value by a dotted path, e.g. `language.name`.
+ */
+function getField(row: Record<string, unknown>, field: string): unknown {
+  return field.split(".").reduce<unknown>(
+    (value, key) => value != null && typeof value === "object" ? (value as Record<string, unknown>)[key] : undefined,
+    row,
+  );
+}
+
+/**
+ * Formats a value as a CSV field.
+ *
+ * @param {unknown} value - The value, objects are serialized as JSON.
+ * @returns {string} The field, quoted when needed.
+ */
+export function toCsvValue(value: unknown): string {
+  if (value == null) {
+    return "";
+  }
+
+  let text = typeof value === "object" ? JSON.stringify(value) : String(value);
+
+  // spreadsheets run cells starting with these as formulas, and the values come from third-party repositories.
+  if (/^[=+\-@\t\r]/.test(text)) {
+    text = `'${text}`;
+  }
+
+  // quote values containing a delimiter, quote or line break, as described in RFC 4180.
+  return /[",\r\n]/.test(text) ? `"${text.replace(/"/g, "\"\"")}"` : text;
+}
+
+/**
+ * Parses the items of a JSON array one at a time while the body is read, so the whole array is never held in memory.
+ * Reads the top-level array, or the first array inside the top-level object, e.g. `{ "projects": [] }`.
+ *
+ * @param {ReadableStream<Uint8Array>} body - The JSON body.
+ * @returns {AsyncGenerator<unknown>} The items of the array.
+ */
+export async function* parseJsonArrayStream(body: ReadableStream<Uint8Array>): AsyncGenerator<unknown> {
+  const reader = body.getReader();
+  const decoder = new TextDecoder();
+
+  let depth = 0;
+  let arrayDepth: number | undefined;
+  let inString = false;
+  let escaped = false;
+  let item = "";
+
+  try {
+    while (true) {
+      const { value, done } = await reader.read();
+
+      if (done) {
+        return;
+      }
+
+      const chunk = decoder.decode(value, { stream: true });
+      let start = 0;
+
+      for (let index = 0; index < chunk.length; index++) {
+        const char = chunk[index];
+
+        if (inString) {
+          if (escaped) {
+            escaped = false;
+          } else if (char === "\\") {
+            escaped = true;
+          } else if (char === "\"") {
+            inString = false;
+          }
+          continue;
+        }
+
+        if (char === "\"") {
+          inString = true;
+        } else if (char === "[" || char === "{") {
+          depth++;
+
+          if (arrayDepth === undefined && char === "[" && depth <= 2) {
+            arrayDepth = depth;
+            start = index + 1;
+          }
+        } else if (char === "]" || char === "}") {
+          if (depth === arrayDepth) {
+            item += chunk.slice(start, index);
+
+            if (item.trim()) {
+              yield JSON.parse(item);
+            }
+
+            return;
+          }
+
+          depth--;
+        } else if (char === "," && depth === arrayDepth) {
+          item += chunk.slice(start, index);
+          yield JSON.parse(item);
+          item = "";
+          start = index + 1;
+        }
+      }
+
+      if (arrayDepth !== undefined) {
+        item += chunk.slice(start);
+      }
+    }
+  } finally {
+    // hands the body back to the caller, which can cancel it again without it being locked.
+    await reader.cancel().catch(() => {});
+    reader.releaseLock();
+  }
+}
+
+async function* toAsyncIterable<T>(rows: Iterable<T> | AsyncIterable<T>): AsyncGenerator<T> {
+  yield* rows;
+}
+
+/**
+ * Streams rows as CSV or NDJSON, one row at a time, with only the selected fields.
+ *
+ * @param {H3Event} event - The request event.
+ * @param {Iterable<Record<string, unknown>> | AsyncIterable<Record<string, unknown>>} rows - The rows to export, read as the response is sent.
+ * @param {Exclude<ExportFormat, "json">} format - The format of the response.
+ * @param {string[]} [fields] - The fields to include, every top-level field of the first row by default.
+ * @returns {Promise<void>} A promise that resolves when the response is sent.
+ */
+export function sendExport(
+  event: H3Event,
+  rows: Iterable<Record<string, unknown>> | AsyncIterable<Record<string, unknown>>,
+  format: Exclude<ExportFormat, "json">,
+  fields?: string[],
+): Promise<void> {
+  const iterator = toAsyncIterable(rows);
+  const encoder = new TextEncoder();
+  let columns: string[] | undefined;
+
+  const stream = new ReadableStream<Uint8Array>({
+    async pull(controller) {
+      const { value: row, done } = await iterator.next();
+
+      if (!columns) {
+        columns = fields?.length ? fields : Object.keys(row ?? {});
+
+        if (format === "csv") {
+          controller.enqueue(encoder.encode(`${columns.map(toCsvValue).join(",")}\r\n`));
+        }
+      }
+
+      if (done) {
+        controller.close();
+        return;
+      }
+
+      const line = format === "csv"
+        ? `${columns.map((column) => toCsvValue(getField(row, column))).join(",")}\r\n`
+        : `${JSON.stringify(Object.fromEntries(columns.map((column) => [column, getField(row, column) ?? null])))}\n`;
+
+      controller.enqueue(encoder.encode(line));
+    },
+    async cancel() {
+      await iterator.return(undefined);
+    },
+  });
+
+  setResponseHeader(event, "Content-Type", EXPORT_CONTENT_TYPES[format]);
+
+  if (format === "csv") {
+    setResponseHeader(event, "Content-Disposition", "attachment; filename=\"projects.csv\"");
+  }
+
+  return sendStream(event, stream);
+}
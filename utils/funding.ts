@@ -0,0 +1,71 @@
+export interface FundingLink {
+  /**
+   * The platform as named in `FUNDING.yml`, lowercased, e.g. `github`, `ko_fi` or `custom`.
+   */
+  platform: string;
+  url: string;
+}
+
+export interface ProjectFunding {
+  owner: string;
+  repository: string;
+  /**
+   * Whether the owner has a GitHub Sponsors profile.
+   */
+  sponsorable: boolean;
+  links: FundingLink[];
+  updatedAt: string;
+}
+
+const PROJECT_FUNDING_PREFIX = "project-funding";
+
+/**
+ * Collects the funding links of a repository without storing them. GitHub parses `.github/FUNDING.yml`,
+ * including the one inherited from the `.github` repository of the owner.
+ *
+ * @param {NonNullable<Awaited<ReturnType<typeof getRepository>>>} repository - The repository, as returned by `getRepository`.
+ * @returns {ProjectFunding} The funding of the repository.
+ */
+export function collectProjectFunding(
+  repository: NonNullable<Awaited<ReturnType<typeof getRepository>>>,
+): ProjectFunding {
+  const sponsorable = "hasSponsorsListing" in repository.owner && repository.owner.hasSponsorsListing === true;
+
+  const links: FundingLink[] = repository.fundingLinks.map((link) => ({
+    platform: link.platform.toLowerCase(),
+    url: link.url,
+  }));
+
+  // owners with a sponsors profile can be sponsored, even without listing it in `FUNDING.yml`.
+  if (sponsorable && !links.some((link) => link.platform === "github")) {
+    links.unshift({ platform: "github", url: `https://github.com/sponsors/${repository.owner.login}` });
+  }
+
+  return {
+    owner: repository.owner.login,
+    repository: repository.name,
+    sponsorable,
+    links,
+    updatedAt: new Date().toISOString(),
+  };
+}
+
+/**
+ * Collects the funding links of a repository, as described in `collectProjectFunding`, and stores them.
+ *
+ * @param {NonNullable<Awaited<ReturnType<typeof getRepository>>>} repository - The repository, as returned by `getRepository`.
+ * @returns {Promise<ProjectFunding>} A promise that resolves to the stored funding.
+ */
+export async function resolveProjectFunding(
+  repository: NonNullable<Awaited<ReturnType<typeof getRepository>>>,
+): Promise<ProjectFunding> {
+  const funding = collectProjectFunding(repository);
+
+  await useStorage("mosaic").setItem(`${PROJECT_FUNDING_PREFIX}:${funding.owner}:${funding.repository}`, funding);
+
+  return funding;
+}
+
+export async function getProjectFunding(owner: string, repository: string): Promise<ProjectFunding | null> {
+  return await useStorage("mosaic").getItem<ProjectFunding>(`${PROJECT_FUNDING_PREFIX}:${owner}:${repository}`);
+}
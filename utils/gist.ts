@@ -0,0 +1,96 @@
+import { z } from "zod";
+
+const GIST_SCHEMA = z.object({
+  id: z.string(),
+  description: z.string().nullable(),
+  html_url: z.string(),
+  public: z.boolean(),
+  created_at: z.string(),
+  updated_at: z.string(),
+  owner: z
+    .object({
+      login: z.string(),
+    })
+    .nullish(),
+  files: z.record(
+    z
+      .object({
+        filename: z.string(),
+        language: z.string().nullable(),
+        raw_url: z.string(),
+        size: z.number(),
+        content: z.string().optional(),
+      })
+      .nullable(),
+  ),
+});
+
+export type Gist = z.infer<typeof GIST_SCHEMA>;
+
+/**
+ * Retrieves a GitHub Gist, including the content of its files.
+ *
+ * @param {string} id - The id of the gist.
+ * @returns {Promise<Gist | undefined>} A promise that resolves to the gist, or `undefined` if it could not be found.
+ * @throws {GitHubError} An error classifying why the gist could not be fetched, for anything but a 404.
+ */
+export async function getGist(id: string): Promise<Gist | undefined> {
+  if (!id) {
+    return undefined;
+  }
+
+  const data = await withSpan("github.gist", { "github.gist": id }, () => githubFetchOptional(`/gists/${id}`));
+
+  if (!data) {
+    return undefined;
+  }
+
+  const result = await GIST_SCHEMA.safeParseAsync(data);
+
+  if (!result.success) {
+    console.error(`invalid github api response for gist ${id}`, result.error);
+    return undefined;
+  }
+
+  return result.data;
+}
+
+/**
+ * Resolves the config of a gist. Gists can't contain a `.github/mosaic.toml`,
+ * so their configs live in the luxass/luxass repository at `.github/mosaic/gists/<id>.toml`.
+ *
+ * @param {string} id - The id of the gist.
+ * @returns {ReturnType<typeof resolveMosaicConfig>} The resolved config.
+ */
+export async function resolveGistConfig(id: string): ReturnType<typeof resolveMosaicConfig> {
+  const resolvedMosaicConfig = await resolveMosaicConfig("gists", id);
+
+  if (
+    resolvedMosaicConfig.type === "resolved"
+    && (resolvedMosaicConfig.content.source?.type !== "gist" || resolvedMosaicConfig.content.source.id !== id)
+  ) {
+    return {
+      type: "error",
+      message: `config for gist ${id} must set \`source = { type = "gist", id = "${id}" }\``,
+    };
+  }
+
+  return resolvedMosaicConfig;
+}
+
+/**
+ * Lists the ids of every gist that has a config.
+ *
+ * @returns {Promise<string[]>} A promise that resolves to the gist ids.
+ */
+export async function getConfiguredGists(): Promise<string[]> {
+  const ids: string[] = [];
+
+  for await (const path of getExternalRepositories(".github/mosaic/gists")) {
+    if (path.endsWith(".toml")) {
+      ids.push(path.split("/").pop()!.slice(0, -5));
+    }
+  }
+
+  return ids;
+}
@@ -0,0 +1,297 @@
+import type { ApiErrorCode } from "./errors";
+
+export type GitHubErrorKind =
+  | "not_found"
+  | "rate_limited"
+  | "abuse_detected"
+  | "unauthorized"
+  | "graphql_errors"
+  | "deserialize"
+  | "upstream";
+
+export interface GraphqlErrorItem {
+  type?: string;
+  message: string;
+  path?: (string | number)[];
+}
+
+/**
+ * An error talking to GitHub, classified so it can be surfaced with the right status.
+ * A missing file should be a 404, while an expired token is our problem and a 502.
+ */
+export class GitHubError extends Error {
+  readonly kind: GitHubErrorKind;
+  readonly url: string;
+  readonly status?: number;
+  /**
+   * Seconds until the request can be retried, for rate limits.
+   */
+  readonly retryAfter?: number;
+  readonly errors?: GraphqlErrorItem[];
+
+  constructor(kind: GitHubErrorKind, message: string, options: {
+    url: string;
+    status?: number;
+    retryAfter?: number;
+    errors?: GraphqlErrorItem[];
+    cause?: unknown;
+  }) {
+    super(message, { cause: options.cause });
+    this.name = "GitHubError";
+    this.kind = kind;
+    this.url = options.url;
+    this.status = options.status;
+    this.retryAfter = options.retryAfter;
+    this.errors = options.errors;
+  }
+}
+
+export interface GitHubFetchOptions {
+  signal?: AbortSignal;
+  /**
+   * The token to authenticate with, defaults to `github.token`.
+   */
+  token?: string;
+}
+
+/**
+ * Reads how many seconds to wait before retrying a request, from `retry-after` or the rate limit reset.
+ *
+ * @param {Headers | Record<string, string | undefined>} headers - The headers of the response.
+ * @returns {number | undefined} The seconds to wait, or `undefined` if the headers don't say.
+ */
+export function getRetryAfter(headers: Headers | Record<string, string | undefined>): number | undefined {
+  const get = (name: string) => headers instanceof Headers ? headers.get(name) : headers[name];
+
+  // `retry-after` is either a number of seconds, or an http date.
+  const retryAfter = get("retry-after");
+  if (retryAfter) {
+    const seconds = /^\d+$/.test(retryAfter.trim())
+      ? Number(retryAfter)
+      : Math.ceil((Date.parse(retryAfter) - Date.now()) / 1000);
+
+    return Number.isFinite(seconds) ? Math.max(0, seconds) : undefined;
+  }
+
+  const reset = get("x-ratelimit-reset");
+  if (reset) {
+    const seconds = Number(reset) - Math.floor(Date.now() / 1000);
+    return Number.isFinite(seconds) ? Math.max(0, seconds) : undefined;
+  }
+
+  return undefined;
+}
+
+/**
+ * Classifies a failed GitHub REST response.
+ *
+ * @param {string} url - The requested url.
+ * @param {number} status - The status of the response.
+ * @param {Headers | Record<string, string | undefined>} headers - The headers of the response.
+ * @param {string} [message] - The `message` of the response body.
+ * @returns {GitHubError} The classified error.
+ */
+export function classifyGitHubResponse(
+  url: string,
+  status: number,
+  headers: Headers | Record<string, string | undefined>,
+  message: string = "",
+): GitHubError {
+  const get = (name: string) => headers instanceof Headers ? headers.get(name) : headers[name];
+  const options = { url, status, retryAfter: getRetryAfter(headers) };
+
+  if (status === 404) {
+    return new GitHubError("not_found", `github resource not found: ${url}`, options);
+  }
+
+  if (status === 401) {
+    return new GitHubError("unauthorized", "github token is invalid or expired", options);
+  }
+
+  if (status === 403 || status === 429) {
+    if (get("x-ratelimit-remaining") === "0") {
+      return new GitHubError("rate_limited", "github rate limit exceeded", options);
+    }
+
+    // secondary rate limits are sent with a `retry-after` header, or mention it in the message.
+    if (get("retry-after") || /secondary rate limit|abuse/i.test(message)) {
+      return new GitHubError("abuse_detected", "github secondary rate limit exceeded", options);
+    }
+
+    return new GitHubError("unauthorized", `github token is not allowed to access ${url}`, options);
+  }
+
+  return new GitHubError("upstream", `github responded with ${status}${message ? `: ${message}` : ""}`, options);
+}
+
+async function githubRequest<T>(
+  path: string,
+  options: GitHubFetchOptions,
+): Promise<{ body: T; headers: Headers }> {
+  const runtimeConfig = useRuntimeConfig();
+  const url = path.startsWith("https://") ? path : `https://api.github.com${path}`;
+
+  const res = await fetch(url, {
+    headers: {
+      "Authorization": `Bearer ${options.token || runtimeConfig.github.token}`,
+      "Content-Type": "application/vnd.github+json",
+      "X-GitHub-Api-Version": "2022-11-28",
+    },
+    signal: options.signal,
+  });
+
+  const text = await res.text();
+
+  let body: unknown;
+  try {
+    body = JSON.parse(text);
+  } catch (err) {
+    if (!res.ok) {
+      throw classifyGitHubResponse(url, res.status, res.headers);
+    }
+
+    throw new GitHubError("deserialize", `invalid json from github for ${url}`, { url, status: res.status, cause: err });
+  }
+
+  if (!res.ok) {
+    const message = body && typeof body === "object" && "message" in body && typeof body.message === "string" ? body.message : undefined;
+    throw classifyGitHubResponse(url, res.status, res.headers, message);
+  }
+
+  return { body: body as T, headers: res.headers };
+}
+
+/**
+ * Fetches a GitHub REST endpoint, throwing a `GitHubError` for every failed or malformed response.
+ *
+ * @param {string} path - The path of the endpoint, e.g. `/repos/luxass/mosaic`, or a full url.
+ * @param {GitHubFetchOptions} [options] - Options for the request.
+ * @returns {Promise<T>} A promise that resolves to the parsed response body.
+ * @throws {GitHubError} An error classifying why the request failed.
+ */
+export async function githubFetch<T = unknown>(
+  path: string,
+  options: GitHubFetchOptions = {},
+): Promise<T> {
+  return (await githubRequest<T>(path, options)).body;
+}
+
+/**
+ * Retrieves the url of the next page from a GitHub `Link` header.
+ *
+ * @param {string | null} link - The value of the `Link` header.
+ * @returns {string | undefined} The url of the next page, or `undefined` if this is the last page.
+ */
+export function getNextPageUrl(link: string | null): string | undefined {
+  if (!link) {
+    return undefined;
+  }
+
+  const match = link.split(",").map((part) => part.match(/<([^>]+)>;\s*rel="next"/)).find(Boolean);
+  return match?.[1];
+}
+
+/**
+ * Fetches every page of a GitHub REST list endpoint, following the `Link` header.
+ *
+ * @param {string} path - The path of the endpoint, e.g. `/repos/luxass/mosaic/releases?per_page=100`.
+ * @param {GitHubFetchOptions & { maxPages?: number }} [options] - Options for the request, `maxPages` defaults to 10.
+ * @returns {Promise<T[]>} A promise that resolves to the items of every page.
+ * @throws {GitHubError} An error classifying why a request failed.
+ */
+export async function githubFetchAll<T = unknown>(
+  path: string,
+  options: GitHubFetchOptions & { maxPages?: number } = {},
+): Promise<T[]> {
+  const items: T[] = [];
+  let url: string | undefined = path;
+
+  for (let page = 0; url && page < (options.maxPages ?? 10); page++) {
+    const { body, headers } = await githubRequest<T[]>(url, options);
+
+    if (!Array.isArray(body)) {
+      throw new GitHubError("deserialize", `expected a list from github for ${url}`, { url });
+    }
+
+    items.push(...body);
+    url = getNextPageUrl(headers.get("link"));
+  }
+
+  return items;
+}
+
+/**
+ * Like `githubFetch`, but resolves to `undefined` when the resource does not exist.
+ *
+ * @param {string} path - The path of the endpoint, e.g. `/repos/luxass/mosaic/contents/package.json`.
+ * @param {GitHubFetchOptions} [options] - Options for the request.
+ * @returns {Promise<T | undefined>} A promise that resolves to the parsed response body, or `undefined` on a 404.
+ * @throws {GitHubError} An error classifying why the request failed, for anything but a 404.
+ */
+export async function githubFetchOptional<T = unknown>(
+  path: string,
+  options: GitHubFetchOptions = {},
+): Promise<T | undefined> {
+  try {
+    return await githubFetch<T>(path, options);
+  } catch (err) {
+    if (err instanceof GitHubError && err.kind === "not_found") {
+      return undefined;
+    }
+
+    throw err;
+  }
+}
+
+/**
+ * Converts an error thrown by `@octokit/graphql` into a `GitHubError`.
+ *
+ * @param {unknown} err - The thrown error.
+ * @returns {GitHubError | undefined} The classified error, or `undefined` if it did not come from octokit.
+ */
+export function fromGraphqlError(err: unknown): GitHubError | undefined {
+  if (!err || typeof err !== "object") {
+    return undefined;
+  }
+
+  const url = "https://api.github.com/graphql";
+
+  // `GraphqlResponseError`, the request succeeded but the query returned errors.
+  if ("errors" in err && Array.isArray(err.errors)) {
+    const errors = err.errors as GraphqlErrorItem[];
+
+    if (errors.some((error) => error.type === "RATE_LIMITED")) {
+      return new GitHubError("rate_limited", "github graphql rate limit exceeded", { url, errors, cause: err });
+    }
+
+    if (errors.every((error) => error.type === "NOT_FOUND")) {
+      return new GitHubError("not_found", errors[0]?.message || "github resource not found", { url, errors, cause: err });
+    }
+
+    return new GitHubError("graphql_errors", errors.map((error) => error.message).join(", "), { url, errors, cause: err });
+  }
+
+  // `RequestError`, the request itself failed.
+  if ("status" in err && typeof err.status === "number") {
+    const response = "response" in err ? err.response as { headers?: Record<string, string> } | undefined : undefined;
+    const classified = classifyGitHubResponse(url, err.status, response?.headers ?? {}, err instanceof Error ? err.message : "");
+    return new GitHubError(classified.kind, classified.message, { url, status: err.status, retryAfter: classified.retryAfter, cause: err });
+  }
+
+  return undefined;
+}
+
+const GITHUB_ERROR_STATUSES: Record<GitHubErrorKind, { status: number; code: ApiErrorCode }> = {
+  not_found: { status: 404, code: "github_not_found" },
+  rate_limited: { status: 429, code: "github_rate_limited" },
+  abuse_detected: { status: 429, code: "github_abuse_detected" },
+  // the token is ours, so the client can't fix it by authenticating.
+  unauthorized: { status: 502, code: "github_unauthorized" },
+  graphql_errors: { status: 502, code: "github_graphql_error" },
+  deserialize: { status: 502, code: "upstream_error" },
+  upstream: { status: 502, code: "upstream_error" },
+};
+
+export function getGitHubErrorStatus(error: GitHubError): { status: number; code: ApiErrorCode } {
+  return GITHUB_ERROR_STATUSES[error.kind];
+}
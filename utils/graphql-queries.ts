@@ -80,3 +80,51 @@ export const PROFILE_CONTRIBUTIONS_QUERY = gql`
     }
   }
 `;
+
+export const PROFILE_VIEWER_QUERY = gql`
+  #graphql
+  ${REPOSITORY_FRAGMENT}
+
+  query getProfileViewer {
+    viewer {
+      login
+      name
+      bio
+      avatarUrl
+      url
+      websiteUrl
+      location
+      company
+      twitterUsername
+      status {
+        emoji
+        message
+        indicatesLimitedAvailability
+      }
+      followers {
+        totalCount
+      }
+      following {
+        totalCount
+      }
+      pinnedItems(first: 6, types: [REPOSITORY]) {
+        nodes {
+          ...RepositoryFragment
+        }
+      }
+      socialAccounts(first: 10) {
+        nodes {
+          provider
+          displayName
+          url
+        }
+      }
+      sponsorsListing {
+        name
+        url
+        shortDescription
+        isPublic
+      }
+    }
+  }
+`;
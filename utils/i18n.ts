@@ -0,0 +1,87 @@
+import type { H3Event } from "h3";
+
+/**
+ * A string in `mosaic.toml`, either the same for every locale or a map of locale to string.
+ */
+export type LocalizedString = string | Record<string, string>;
+
+/**
+ * Cache options for handlers responding with localized strings, spread into the options of `defineCachedEventHandler`.
+ * `?lang=` is part of the cache key like every query, but `Accept-Language` is only part of it when the cache varies on it.
+ */
+export const LOCALIZED_CACHE_OPTIONS = {
+  varies: ["accept-language"],
+};
+
+/**
+ * The locales requested by the client, most preferred first. `?lang=` wins over `Accept-Language`.
+ *
+ * @param {H3Event} event - The request event.
+ * @returns {string[]} The requested locales, lowercased, e.g. `["da-dk", "da", "en"]`.
+ */
+export function getRequestLocales(event: H3Event): string[] {
+  const { lang } = getQuery(event);
+
+  if (typeof lang === "string" && lang) {
+    return lang.toLowerCase().split(",").map((locale) => locale.trim()).filter(Boolean);
+  }
+
+  const header = getRequestHeader(event, "accept-language") || "";
+
+  return header
+    .split(",")
+    .map((part) => {
+      const [locale, ...params] = part.trim().split(";");
+      const q = params.find((param) => param.trim().startsWith("q="));
+      return { locale: locale.trim().toLowerCase(), quality: q ? Number(q.trim().slice(2)) : 1 };
+    })
+    .filter(({ locale, quality }) => locale && locale !== "*" && quality > 0)
+    .sort((a, b) => b.quality - a.quality)
+    .map(({ locale }) => locale);
+}
+
+/**
+ * Picks the best matching translation of a localized string. A region falls back to its language,
+ * e.g. `da-DK` to `da`, and when nothing matches the default locale, then the first translation, is used.
+ *
+ * @param {LocalizedString | undefined} value - The localized string.
+ * @param {string[]} [locales] - The requested locales, most preferred first.
+ * @returns {string | undefined} The translation, or `undefined` if the value is not set.
+ */
+export function localize(value: LocalizedString | undefined, locales: string[] = []): string | undefined {
+  if (value == null || typeof value === "string") {
+    return value;
+  }
+
+  const translations = Object.fromEntries(Object.entries(value).map(([locale, text]) => [locale.toLowerCase(), text]));
+  const { i18n } = useRuntimeConfig();
+
+  for (const locale of [...locales, i18n.defaultLocale]) {
+    const text = translations[locale] ?? translations[locale.split("-")[0]];
+    if (text != null) {
+      return text;
+    }
+  }
+
+  return Object.values(translations)[0];
+}
+
+/**
+ * Lists the locales a set of localized strings are available in.
+ *
+ * @param {(LocalizedString | undefined)[]} values - The localized strings.
+ * @returns {string[]} The locales, sorted. Plain strings don't add any locale.
+ */
+export function getAvailableLocales(...values: (LocalizedString | undefined)[]): string[] {
+  const locales = new Set<string>();
+
+  for (const value of values) {
+    if (value && typeof value === "object") {
+      for (const locale of Object.keys(value)) {
+        locales.add(locale);
+      }
+    }
+  }
+
+  return [...locales].sort();
+}
@@ -0,0 +1,158 @@
+import type { Page, PageOptions } from "./pagination";
+
+export const INBOX_SOURCES = ["report", "link_check", "job"] as const;
+export const INBOX_STATUSES = ["new", "acked", "resolved"] as const;
+
+export type InboxSource = typeof INBOX_SOURCES[number];
+export type InboxStatus = typeof INBOX_STATUSES[number];
+
+export interface InboxItem {
+  id: string;
+  source: InboxSource;
+  status: InboxStatus;
+  title: string;
+  owner?: string;
+  repository?: string;
+  /**
+   * Items with the same key are merged while unresolved, so a recurring problem shows up once.
+   */
+  dedupeKey?: string;
+  /**
+   * The number of times the item was reported.
+   */
+  occurrences: number;
+  data?: unknown;
+  createdAt: string;
+  updatedAt: string;
+}
+
+export interface InboxFilter {
+  status?: InboxStatus[];
+  source?: InboxSource[];
+  owner?: string;
+  repository?: string;
+}
+
+export type AddInboxItemOptions = Pick<InboxItem, "source" | "title" | "owner" | "repository" | "dedupeKey" | "data">;
+
+const INBOX_PREFIX = "inbox";
+const INBOX_DEDUPE_PREFIX = "inbox-dedupe";
+
+/**
+ * Adds an item to the admin inbox. When an unresolved item with the same dedupe key exists,
+ * it is updated instead, keeping its status.
+ *
+ * @param {AddInboxItemOptions} options - The item to add.
+ * @returns {Promise<InboxItem>} A promise that resolves to the stored item.
+ */
+export async function addInboxItem(options: AddInboxItemOptions): Promise<InboxItem> {
+  const storage = useStorage("mosaic");
+  const now = new Date().toISOString();
+
+  if (options.dedupeKey) {
+    const existingId = await storage.getItem<string>(`${INBOX_DEDUPE_PREFIX}:${options.dedupeKey}`);
+    const existing = existingId ? await getInboxItem(existingId) : null;
+
+    if (existing && existing.status !== "resolved") {
+      const updated: InboxItem = {
+        ...existing,
+        title: options.title,
+        data: options.data,
+        occurrences: existing.occurrences + 1,
+        updatedAt: now,
+      };
+
+      await storage.setItem(`${INBOX_PREFIX}:${updated.id}`, updated);
+      return updated;
+    }
+  }
+
+  const item: InboxItem = {
+    // sortable by creation time, so the newest items can be listed first.
+    id: `${Date.now()}-${crypto.randomUUID().slice(0, 8)}`,
+    status: "new",
+    occurrences: 1,
+    createdAt: now,
+    updatedAt: now,
+    ...options,
+  };
+
+  await storage.setItem(`${INBOX_PREFIX}:${item.id}`, item);
+
+  if (item.dedupeKey) {
+    await storage.setItem(`${INBOX_DEDUPE_PREFIX}:${item.dedupeKey}`, item.id);
+  }
+
+  return item;
+}
+
+export async function getInboxItem(id: string): Promise<InboxItem | null> {
+  return await useStorage("mosaic").getItem<InboxItem>(`${INBOX_PREFIX}:${id}`);
+}
+
+export function matchesInboxFilter(item: InboxItem, filter: InboxFilter): boolean {
+  return (!filter.status?.length || filter.status.includes(item.status))
+    && (!filter.source?.length || filter.source.includes(item.source))
+    && (!filter.owner || item.owner?.toLowerCase() === filter.owner.toLowerCase())
+    && (!filter.repository || item.repository?.toLowerCase() === filter.repository.toLowerCase());
+}
+
+export async function getInboxItems(filter: InboxFilter = {}): Promise<InboxItem[]> {
+  const storage = useStorage("mosaic");
+  const keys = (await storage.getKeys(INBOX_PREFIX)).sort().reverse();
+
+  const items = await Promise.all(keys.map((key) => storage.getItem<InboxItem>(key)));
+
+  return items.filter((item): item is InboxItem => item != null && matchesInboxFilter(item, filter));
+}
+
+export async function getInboxPage(filter: InboxFilter, page: PageOptions): Promise<Page<InboxItem>> {
+  return await paginateStorage<InboxItem>(INBOX_PREFIX, page, (item) => matchesInboxFilter(item, filter));
+}
+
+/**
+ * Changes the status of several inbox items at once.
+ *
+ * @param {string[]} ids - The ids of the items.
+ * @param {InboxStatus} status - The new status.
+ * @returns {Promise<InboxItem[]>} A promise that resolves to the updated items, unknown ids are skipped.
+ */
+export async function setInboxStatus(ids: string[], status: InboxStatus): Promise<InboxItem[]> {
+  const storage = useStorage("mosaic");
+  const now = new Date().toISOString();
+
+  const updated = await Promise.all(ids.map(async (id) => {
+    const item = await getInboxItem(id);
+
+    if (!item) {
+      return undefined;
+    }
+
+    const next: InboxItem = { ...item, status, updatedAt: now };
+    await storage.setItem(`${INBOX_PREFIX}:${id}`, next);
+    return next;
+  }));
+
+  return updated.filter((item): item is InboxItem => item != null);
+}
+
+export async function deleteInboxItems(ids: string[]): Promise<number> {
+  const storage = useStorage("mosaic");
+
+  const deleted = await Promise.all(ids.map(async (id) => {
+    const item = await getInboxItem(id);
+
+    if (!item) {
+      return false;
+    }
+
+    await storage.removeItem(`${INBOX_PREFIX}:${id}`);
+    if (item.dedupeKey) {
+      await storage.removeItem(`${INBOX_DEDUPE_PREFIX}:${item.dedupeKey}`);
+    }
+
+    return true;
+  }));
+
+  return deleted.filter(Boolean).length;
+}
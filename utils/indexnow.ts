@@ -0,0 +1,82 @@
+const INDEXNOW_SEEN_PREFIX = "indexnow";
+
+/**
+ * The maximum number of urls IndexNow accepts in a single request.
+ */
+export const INDEXNOW_MAX_URLS = 10_000;
+
+/**
+ * Submits a batch of urls to IndexNow, which forwards them to every participating search engine.
+ *
+ * @param {string[]} urls - The urls to submit, all on the configured host.
+ * @returns {Promise<boolean>} A promise that resolves to `true` if the urls were accepted.
+ */
+export async function submitToIndexNow(urls: string[]): Promise<boolean> {
+  const { indexNow } = useRuntimeConfig();
+
+  if (!indexNow.key || !urls.length) {
+    return false;
+  }
+
+  // https://www.indexnow.org/documentation
+  const res = await fetch("https://api.indexnow.org/indexnow", {
+    method: "POST",
+    headers: {
+      "Content-Type": "application/json; charset=utf-8",
+    },
+    body: JSON.stringify({
+      host: indexNow.host,
+      key: indexNow.key,
+      keyLocation: indexNow.keyLocation || undefined,
+      urlList: urls.slice(0, INDEXNOW_MAX_URLS),
+    }),
+  });
+
+  return res.ok;
+}
+
+export function projectPageUrl(handle: string): string {
+  const { indexNow } = useRuntimeConfig();
+  return indexNow.urlTemplate.replace("{handle}", encodeURIComponent(handle));
+}
+
+export interface IndexNowProject {
+  handle: string;
+  pushedAt: string | null;
+}
+
+/**
+ * Returns the projects that are new or have been pushed to since they were last submitted.
+ * Nothing is remembered until `markProjectsSubmitted` is called, so a failed submission is retried on the next run.
+ *
+ * @param {IndexNowProject[]} projects - The projects of this sync run.
+ * @returns {Promise<IndexNowProject[]>} A promise that resolves to the projects that changed.
+ */
+export async function getChangedProjects(projects: IndexNowProject[]): Promise<IndexNowProject[]> {
+  const storage = useStorage("mosaic");
+  const changed: IndexNowProject[] = [];
+
+  for (const project of projects) {
+    const seen = await storage.getItem<string>(`${INDEXNOW_SEEN_PREFIX}:${project.handle}`);
+
+    if (seen !== project.pushedAt) {
+      changed.push(project);
+    }
+  }
+
+  return changed;
+}
+
+/**
+ * Remembers projects as submitted, once IndexNow accepted them.
+ *
+ * @param {IndexNowProject[]} projects - The submitted projects.
+ * @returns {Promise<void>}
+ */
+export async function markProjectsSubmitted(projects: IndexNowProject[]): Promise<void> {
+  const storage = useStorage("mosaic");
+
+  for (const project of projects) {
+    await storage.setItem(`${INDEXNOW_SEEN_PREFIX}:${project.handle}`, project.pushedAt);
+  }
+}
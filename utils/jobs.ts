@@ -0,0 +1,299 @@
+import type { Page, PageOptions } from "./pagination";
+
+export interface JobRun {
+  name: string;
+  status: "running" | "succeeded" | "failed" | "interrupted";
+  trigger: "scheduled" | "manual";
+  startedAt: string;
+  finishedAt?: string;
+  duration?: number;
+  result?: unknown;
+  error?: string;
+}
+
+export interface SyncFailure {
+  job: string;
+  owner: string;
+  repository: string;
+  error: string;
+  /**
+   * The number of consecutive failed attempts.
+   */
+  count: number;
+  firstFailedAt: string;
+  lastFailedAt: string;
+  /**
+   * When the project is retried next, `null` once the project is dead-lettered.
+   */
+  nextRetryAt: string | null;
+  /**
+   * Dead-lettered projects are not retried until they are retried manually.
+   */
+  deadLettered: boolean;
+}
+
+/**
+ * The tasks that make up the sync, in the order they are run by `POST /api/v1/admin/sync/run`.
+ */
+export const SYNC_JOBS = ["projects:sync", "links:check", "indexnow:submit", "stats:snapshot", "stack:sync"];
+
+const JOBS_PREFIX = "jobs";
+const SYNC_FAILURES_PREFIX = "sync-failures";
+const SCHEDULER_PAUSED_KEY = "scheduler:paused";
+
+// failures are tracked per job, a broken link check shouldn't hold back the stack sync of the same project.
+function syncFailureKey(job: string, owner: string, repository: string): string {
+  return `${SYNC_FAILURES_PREFIX}:${job}:${owner}:${repository}`;
+}
+
+// the runs of this instance, so they can be drained on shutdown.
+const inFlightRuns = new Map<string, { run: JobRun; promise: Promise<unknown> }>();
+let draining = false;
+
+/**
+ * Runs a job and records its status, so it can be inspected through the admin api.
+ * Scheduled runs are skipped while the scheduler is paused, manual runs always run.
+ *
+ * @param {string} name - The name of the job, usually the name of the task.
+ * @param {JobRun["trigger"]} trigger - What started the job.
+ * @param {() => Promise<T>} fn - The job itself.
+ * @returns {Promise<T | undefined>} The result of the job, or `undefined` if it was skipped.
+ */
+export async function runJob<T>(
+  name: string,
+  trigger: JobRun["trigger"],
+  fn: () => Promise<T>,
+): Promise<T | undefined> {
+  const storage = useStorage("mosaic");
+
+  if (draining) {
+    // eslint-disable-next-line no-console
+    console.info(`shutting down, skipping ${name}`);
+    return undefined;
+  }
+
+  if (trigger === "scheduled" && await isSchedulerPaused()) {
+    // eslint-disable-next-line no-console
+    console.info(`scheduler is paused, skipping ${name}`);
+    return undefined;
+  }
+
+  const run: JobRun = {
+    name,
+    status: "running",
+    trigger,
+    startedAt: new Date().toISOString(),
+  };
+  await storage.setItem(`${JOBS_PREFIX}:${name}`, run);
+
+  const start = Date.now();
+
+  // the run is only removed from the in-flight runs once its status is stored,
+  // so draining waits for the store and never overwrites a finished run as interrupted.
+  const promise = (async () => {
+    try {
+      const result = await fn();
+      run.status = "succeeded";
+      run.result = result;
+      return result;
+    } catch (err) {
+      run.status = "failed";
+      run.error = err instanceof Error ? err.message : String(err);
+
+      await addInboxItem({
+        source: "job",
+        title: `${name} failed`,
+        dedupeKey: `job:${name}`,
+        data: { trigger, error: run.error },
+      });
+
+      throw err;
+    } finally {
+      run.finishedAt = new Date().toISOString();
+      run.duration = Date.now() - start;
+      await storage.setItem(`${JOBS_PREFIX}:${name}`, run);
+      inFlightRuns.delete(name);
+    }
+  })();
+  inFlightRuns.set(name, { run, promise });
+
+  return await promise;
+}
+
+/**
+ * Whether the instance is shutting down. Jobs should stop between projects while draining,
+ * so no project is left half-synced.
+ *
+ * @returns {boolean} `true` once draining has started.
+ */
+export function isDraining(): boolean {
+  return draining;
+}
+
+/**
+ * Stops accepting new jobs, and waits for the running jobs to finish and store their status.
+ * Jobs still running after the timeout are stored as interrupted, until they finish.
+ *
+ * @param {number} timeout - How long to wait in milliseconds.
+ * @returns {Promise<{ finished: string[]; interrupted: string[] }>} A promise that resolves to the names of the drained jobs.
+ */
+export async function drainJobs(timeout: number): Promise<{ finished: string[]; interrupted: string[] }> {
+  draining = true;
+
+  const names = [...inFlightRuns.keys()];
+  if (!names.length) {
+    return { finished: [], interrupted: [] };
+  }
+
+  // eslint-disable-next-line no-console
+  console.info(`waiting up to ${timeout}ms for ${names.join(", ")} to finish`);
+
+  await Promise.race([
+    Promise.allSettled([...inFlightRuns.values()].map(({ promise }) => promise)),
+    new Promise((resolve) => setTimeout(resolve, timeout)),
+  ]);
+
+  const storage = useStorage("mosaic");
+  const interrupted = [...inFlightRuns.entries()];
+
+  for (const [name, { run }] of interrupted) {
+    run.status = "interrupted";
+    run.error = "interrupted by shutdown";
+    run.finishedAt = new Date().toISOString();
+    run.duration = Date.now() - new Date(run.startedAt).getTime();
+    await storage.setItem(`${JOBS_PREFIX}:${name}`, run);
+  }
+
+  return {
+    finished: names.filter((name) => !inFlightRuns.has(name)),
+    interrupted: interrupted.map(([name]) => name),
+  };
+}
+
+/**
+ * Retrieves the last run of every sync job that has run.
+ *
+ * @param {PageOptions} page - The page to load.
+ * @returns {Promise<Page<JobRun>>} A promise that resolves to a page of the runs.
+ */
+export async function getJobRuns(page: PageOptions): Promise<Page<JobRun>> {
+  return await paginateStorage<JobRun>(JOBS_PREFIX, page, (run) => SYNC_JOBS.includes(run.name));
+}
+
+export async function isSchedulerPaused(): Promise<boolean> {
+  return (await useStorage("mosaic").getItem<boolean>(SCHEDULER_PAUSED_KEY)) === true;
+}
+
+export async function setSchedulerPaused(paused: boolean): Promise<void> {
+  await useStorage("mosaic").setItem(SCHEDULER_PAUSED_KEY, paused);
+}
+
+/**
+ * Records a failed sync of a project, scheduling the next retry with exponential backoff.
+ * After `sync.maxAttempts` consecutive failures, the project is dead-lettered.
+ *
+ * @param {string} job - The name of the job that failed.
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @param {unknown} err - The error the job failed with.
+ * @returns {Promise<SyncFailure>} A promise that resolves to the updated failure.
+ */
+export async function recordSyncFailure(
+  job: string,
+  owner: string,
+  repository: string,
+  err: unknown,
+): Promise<SyncFailure> {
+  const { sync } = useRuntimeConfig();
+  const storage = useStorage("mosaic");
+  const key = syncFailureKey(job, owner, repository);
+
+  const previous = await storage.getItem<SyncFailure>(key);
+  const now = new Date();
+  const count = (previous?.count || 0) + 1;
+  const deadLettered = count >= sync.maxAttempts;
+
+  const backoff = Math.min(sync.backoffBase * 2 ** (count - 1), sync.backoffMax) * 1000;
+
+  const failure: SyncFailure = {
+    job,
+    owner,
+    repository,
+    error: err instanceof Error ? err.message : String(err),
+    count,
+    firstFailedAt: previous?.firstFailedAt || now.toISOString(),
+    lastFailedAt: now.toISOString(),
+    nextRetryAt: deadLettered ? null : new Date(now.getTime() + backoff).toISOString(),
+    deadLettered,
+  };
+
+  await storage.setItem(key, failure);
+
+  // only dead-lettered projects need attention, everything else is retried automatically.
+  if (deadLettered && !previous?.deadLettered) {
+    await addInboxItem({
+      source: "job",
+      title: `${owner}/${repository} was dead-lettered by ${job}`,
+      owner,
+      repository,
+      dedupeKey: `sync:${job}:${owner}:${repository}`,
+      data: failure,
+    });
+  }
+
+  return failure;
+}
+
+export async function clearSyncFailure(job: string, owner: string, repository: string): Promise<void> {
+  await useStorage("mosaic").removeItem(syncFailureKey(job, owner, repository));
+}
+
+export async function getSyncFailure(job: string, owner: string, repository: string): Promise<SyncFailure | null> {
+  return await useStorage("mosaic").getItem<SyncFailure>(syncFailureKey(job, owner, repository));
+}
+
+/**
+ * Retrieves the failures of every job that failed to sync a project.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<SyncFailure[]>} A promise that resolves to the failures, in the order of `SYNC_JOBS`.
+ */
+export async function getProjectSyncFailures(owner: string, repository: string): Promise<SyncFailure[]> {
+  const failures = await Promise.all(SYNC_JOBS.map((job) => getSyncFailure(job, owner, repository)));
+  return failures.filter((failure): failure is SyncFailure => failure != null);
+}
+
+/**
+ * Retrieves the failures of every job and project.
+ *
+ * @param {PageOptions} page - The page to load.
+ * @param {boolean} [deadLettered] - Only list failures that are, or are not, dead-lettered.
+ * @returns {Promise<Page<SyncFailure>>} A promise that resolves to a page of the failures.
+ */
+export async function getSyncFailures(page: PageOptions, deadLettered?: boolean): Promise<Page<SyncFailure>> {
+  return await paginateStorage<SyncFailure>(
+    SYNC_FAILURES_PREFIX,
+    page,
+    deadLettered == null ? undefined : (failure) => failure.deadLettered === deadLettered,
+  );
+}
+
+/**
+ * Whether a job should sync a project now, or is still backing off from a previous failure of the same job.
+ * A failure of one job does not hold back the other jobs.
+ *
+ * @param {string} job - The name of the job.
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<boolean>} `true` if the project has not failed, or its next retry is due.
+ */
+export async function shouldSync(job: string, owner: string, repository: string): Promise<boolean> {
+  const failure = await getSyncFailure(job, owner, repository);
+
+  if (!failure) {
+    return true;
+  }
+
+  return !failure.deadLettered && failure.nextRetryAt != null && new Date(failure.nextRetryAt) <= new Date();
+}
@@ -1,5 +1,12 @@
 import { z } from "zod";
 
+const LOCALIZED_STRING_SCHEMA = (description: string) => z.union([
+  z.string({ description }),
+  z.record(z.string(), {
+    description: `${description} translated per locale, e.g. \`{ en = "...", da = "..." }\`.`,
+  }),
+]);
+
 export const PROJECT_SCHEMA = z.object({
   priority: z
     .number({
@@ -8,11 +15,7 @@ export const PROJECT_SCHEMA = z.object({
     })
     .default(10),
 
-  description: z
-    .string({
-      description: "the description of the project.",
-    })
-    .optional(),
+  description: LOCALIZED_STRING_SCHEMA("the description of the project.").optional(),
 
   stars: z
     .boolean({
@@ -20,6 +23,12 @@ export const PROJECT_SCHEMA = z.object({
     })
     .default(false),
 
+  downloads: z
+    .boolean({
+      description: "include the total download count of the release assets in the repository.",
+    })
+    .default(false),
+
   version: z
     .boolean({
       description: "infer the version of the project from the repository.",
@@ -34,6 +43,10 @@ export const PROJECT_SCHEMA = z.object({
     .optional()
     .default(false),
 
+  license: z.string({
+    description: "the SPDX id of the license, e.g. `MIT`. by default the license is detected by GitHub.",
+  }).optional(),
+
   handle: z.string({
     description: "the handle of the project. By default the handle will be auto-inferred from the repository name.",
   }).optional(),
@@ -65,9 +78,37 @@ export const README_SCHEMA = z.object({
   path: z
     .string({
       description:
-        "the path to the readme file. by default the `path` will be auto-inferred from the repository.",
+        "the path to the readme file, or a directory containing a `README.md`. by default the `path` will be auto-inferred from the repository.",
     })
     .optional(),
+  docs: z
+    .array(
+      z.union([
+        z.string({
+          description: "the path to a markdown file.",
+        }),
+        z.object({
+          path: z.string({
+            description: "the path to a markdown file.",
+          }),
+          slug: z
+            .string({
+              description: "the slug of the page. by default the `slug` will be inferred from the file name.",
+            })
+            .regex(/^[a-z0-9-]+$/)
+            .optional(),
+          title: z
+            .string({
+              description: "the title of the page. by default the `title` will be inferred from the first heading.",
+            })
+            .optional(),
+        }),
+      ]),
+      {
+        description: "additional markdown files to include as documentation pages.",
+      },
+    )
+    .optional(),
 });
 
 export const WEBSITE_SCHEMA = z.object({
@@ -79,18 +120,16 @@ export const WEBSITE_SCHEMA = z.object({
       description: "the url of the website.",
     })
     .optional(),
-  title: z
-    .string({
-      description: "the title of the website.",
-    })
-    .optional(),
-  description: z
+  title: LOCALIZED_STRING_SCHEMA("the title of the website.").optional(),
+  description: LOCALIZED_STRING_SCHEMA(
+    "The description to set in the meta description tag. If not provided, the description will be inferred from `project.description`",
+  ).optional(),
+  keywords: z.array(z.string()).optional(),
+  vercel: z
     .string({
-      description:
-        "The description to set in the meta description tag. If not provided, the description will be inferred from `project.description`",
+      description: "the name or id of the vercel project deploying the website. used to check the deployment status.",
     })
     .optional(),
-  keywords: z.array(z.string()).optional(),
 });
 
 export const DEPRECATED_SCHEMA = z.object({
@@ -105,6 +144,22 @@ export const DEPRECATED_SCHEMA = z.object({
     .optional(),
 });
 
+export const SOURCE_SCHEMA = z.discriminatedUnion("type", [
+  z.object({
+    type: z.literal("repository", {
+      description: "the project is backed by the repository the config is in.",
+    }),
+  }),
+  z.object({
+    type: z.literal("gist", {
+      description: "the project is backed by a github gist.",
+    }),
+    id: z.string({
+      description: "the id of the gist.",
+    }),
+  }),
+]);
+
 const BASE_MOSAIC_SCHEMA = z.object({
   project: PROJECT_SCHEMA,
   npm: NPM_SCHEMA.optional(),
@@ -152,6 +207,14 @@ export const MOSAIC_SCHEMA = BASE_MOSAIC_SCHEMA.merge(
         }),
       }),
     ),
+    version: z
+      .number({
+        description: "the version of the config format. configs without a version are upgraded from version 1.",
+      })
+      .int()
+      .positive()
+      .optional(),
     workspace: WORKSPACE_SCHEMA.optional(),
+    source: SOURCE_SCHEMA.optional(),
   }),
 );
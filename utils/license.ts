@@ -0,0 +1,170 @@
+import type { OwnerRepository } from "./owners";
+
+export interface ProjectLicense {
+  owner: string;
+  repository: string;
+  /**
+   * The SPDX id of the license, e.g. `MIT`.
+   */
+  spdxId: string;
+  /**
+   * Where the license was detected, `config` when it is overridden in `mosaic.toml`.
+   */
+  source: "config" | "github" | "file";
+  updatedAt: string;
+}
+
+const PROJECT_LICENSES_PREFIX = "project-licenses";
+
+const LICENSE_FILES = ["LICENSE", "LICENSE.md", "LICENSE.txt", "LICENCE", "COPYING"];
+
+/**
+ * Phrases that identify the most common licenses, checked in order.
+ * GitHub detects most licenses itself, so these only cover files it could not match, e.g. with a modified header.
+ */
+const LICENSE_PATTERNS: [spdxId: string, pattern: RegExp][] = [
+  ["AGPL-3.0", /GNU AFFERO GENERAL PUBLIC LICENSE\s+Version 3/i],
+  ["LGPL-3.0", /GNU LESSER GENERAL PUBLIC LICENSE\s+Version 3/i],
+  ["GPL-3.0", /GNU GENERAL PUBLIC LICENSE\s+Version 3/i],
+  ["GPL-2.0", /GNU GENERAL PUBLIC LICENSE\s+Version 2/i],
+  ["Apache-2.0", /Apache License[\s,]+Version 2\.0/i],
+  ["MPL-2.0", /Mozilla Public License,?\s+(?:Version|v\.)\s*2\.0/i],
+  ["Unlicense", /This is free and unencumbered software released into the public domain/i],
+  ["BSD-3-Clause", /Redistribution and use in source and binary forms[\s\S]+Neither the name/i],
+  ["BSD-2-Clause", /Redistribution and use in source and binary forms/i],
+  ["ISC", /Permission to use, copy, modify, and\/or distribute this software for any purpose/i],
+  ["MIT", /Permission is hereby granted, free of charge, to any person obtaining a copy/i],
+];
+
+/**
+ * Detects the license of a license file by its text.
+ *
+ * @param {string} content - The content of the license file.
+ * @returns {string | undefined} The SPDX id of the license, or `undefined` if it is not recognized.
+ */
+export function detectLicense(content: string): string | undefined {
+  return LICENSE_PATTERNS.find(([, pattern]) => pattern.test(content))?.[0];
+}
+
+async function detectLicenseFile(owner: string, repository: string): Promise<string | undefined> {
+  for (const file of LICENSE_FILES) {
+    const result = await githubFetchOptional(`/repos/${owner}/${repository}/contents/${file}`, { token: getOwnerToken(owner) });
+
+    if (result && typeof result === "object" && "content" in result && typeof result.content === "string") {
+      return detectLicense(base64ToString(result.content));
+    }
+  }
+
+  return undefined;
+}
+
+/**
+ * Detects the license of a project without storing it. The license in `mosaic.toml` wins over the one detected by GitHub,
+ * and the license file is only read when GitHub could not detect the license.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @param {string | undefined} override - The license set in `mosaic.toml`.
+ * @param {string | null | undefined} githubSpdxId - The SPDX id detected by GitHub.
+ * @returns {Promise<Pick<ProjectLicense, "spdxId" | "source"> | undefined>} A promise that resolves to the license, or `undefined` if the project has none.
+ */
+export async function detectProjectLicense(
+  owner: string,
+  repository: string,
+  override: string | undefined,
+  githubSpdxId: string | null | undefined,
+): Promise<Pick<ProjectLicense, "spdxId" | "source"> | undefined> {
+  let spdxId = override;
+  let source: ProjectLicense["source"] = "config";
+
+  // github uses `NOASSERTION` when a license file exists, but could not be matched.
+  if (!spdxId && githubSpdxId && githubSpdxId !== "NOASSERTION") {
+    spdxId = githubSpdxId;
+    source = "github";
+  }
+
+  if (!spdxId) {
+    spdxId = await detectLicenseFile(owner, repository).catch(() => undefined);
+    source = "file";
+  }
+
+  return spdxId ? { spdxId, source } : undefined;
+}
+
+/**
+ * Detects the license of a project, as described in `detectProjectLicense`, and stores it.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @param {string | undefined} override - The license set in `mosaic.toml`.
+ * @param {string | null | undefined} githubSpdxId - The SPDX id detected by GitHub.
+ * @returns {Promise<ProjectLicense | undefined>} A promise that resolves to the license, or `undefined` if the project has none.
+ */
+export async function resolveProjectLicense(
+  owner: string,
+  repository: string,
+  override: string | undefined,
+  githubSpdxId: string | null | undefined,
+): Promise<ProjectLicense | undefined> {
+  const detected = await detectProjectLicense(owner, repository, override, githubSpdxId);
+
+  const storage = useStorage("mosaic");
+  const key = `${PROJECT_LICENSES_PREFIX}:${owner}:${repository}`;
+
+  if (!detected) {
+    await storage.removeItem(key);
+    return undefined;
+  }
+
+  const license: ProjectLicense = {
+    owner,
+    repository,
+    ...detected,
+    updatedAt: new Date().toISOString(),
+  };
+
+  await storage.setItem(key, license);
+
+  return license;
+}
+
+export async function getProjectLicense(owner: string, repository: string): Promise<ProjectLicense | undefined> {
+  const storage = useStorage("mosaic");
+  return await storage.getItem<ProjectLicense>(`${PROJECT_LICENSES_PREFIX}:${owner}:${repository}`) ?? undefined;
+}
+
+export async function getProjectLicenses(): Promise<ProjectLicense[]> {
+  const storage = useStorage("mosaic");
+  const keys = await storage.getKeys(PROJECT_LICENSES_PREFIX);
+
+  const licenses = await Promise.all(keys.map((key) => storage.getItem<ProjectLicense>(key)));
+  return licenses.filter((license): license is ProjectLicense => license != null);
+}
+
+/**
+ * Reads the stored license of every project.
+ *
+ * @returns {Promise<Map<string, string>>} A promise that resolves to the SPDX id keyed by the lowercased `owner/name`.
+ */
+export async function getStoredLicenses(): Promise<Map<string, string>> {
+  return new Map((await getProjectLicenses()).map((license) => [
+    `${license.owner}/${license.repository}`.toLowerCase(),
+    license.spdxId,
+  ]));
+}
+
+/**
+ * Resolves the license of every discovered repository, preferring the stored license of a project,
+ * which includes overrides from `mosaic.toml`, over the license GitHub detected.
+ *
+ * @param {OwnerRepository[]} repositories - The discovered repositories.
+ * @returns {Promise<Map<string, string | null>>} A promise that resolves to the SPDX id keyed by `owner/name`.
+ */
+export async function getRepositoryLicenses(repositories: OwnerRepository[]): Promise<Map<string, string | null>> {
+  const stored = await getStoredLicenses();
+
+  return new Map(repositories.map((repository) => [
+    repository.nameWithOwner,
+    stored.get(repository.nameWithOwner.toLowerCase()) ?? repository.license,
+  ]));
+}
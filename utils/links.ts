@@ -0,0 +1,304 @@
+import type { Root } from "mdast";
+import { remark } from "remark";
+import { visit } from "unist-util-visit";
+import { isBadge } from "~/transformers/badge-remover";
+
+export interface BadgeCheck {
+  url: string;
+  ok: boolean;
+  status?: number;
+  reason?: string;
+}
+
+// messages rendered inside of shields-style badges, when the badge could not be resolved.
+const BROKEN_BADGE_MESSAGES = [
+  "repo not found",
+  "package not found",
+  "not found",
+  "inaccessible",
+  "invalid",
+  "no releases",
+  "no tags",
+  "unknown",
+];
+
+export function isBadgeUrl(url: string): boolean {
+  // GitHub Actions workflow badges are not covered by the badge remover.
+  return isBadge(url) || /^https:\/\/github\.com\/[^/]+\/[^/]+\/actions\/workflows\/[^/]+\/badge\.svg/.test(url);
+}
+
+/**
+ * Extracts every badge image url from a markdown document.
+ *
+ * @param {string} markdown - The markdown document, usually a README.
+ * @returns {string[]} The unique badge urls in the order they appear.
+ */
+export function extractBadgeUrls(markdown: string): string[] {
+  const tree = remark().parse(markdown) as Root;
+  const urls = new Set<string>();
+
+  visit(tree, ["image", "definition"], (node) => {
+    if ((node.type === "image" || node.type === "definition") && isBadgeUrl(node.url)) {
+      urls.add(node.url);
+    }
+  });
+
+  return [...urls];
+}
+
+/**
+ * Checks whether a badge image resolves and does not render an error message.
+ *
+ * @param {string} url - The url of the badge image.
+ * @returns {Promise<BadgeCheck>} A promise that resolves to the result of the check.
+ */
+export async function checkBadge(url: string): Promise<BadgeCheck> {
+  try {
+    const res = await fetch(url, {
+      redirect: "follow",
+      signal: AbortSignal.timeout(10_000),
+    });
+
+    if (!res.ok) {
+      return { url, ok: false, status: res.status, reason: `badge responded with ${res.status}` };
+    }
+
+    const contentType = res.headers.get("content-type") || "";
+
+    if (contentType.includes("svg")) {
+      const svg = (await res.text()).toLowerCase();
+      const message = BROKEN_BADGE_MESSAGES.find((message) => svg.includes(`>${message}<`));
+
+      if (message) {
+        return { url, ok: false, status: res.status, reason: `badge renders "${message}"` };
+      }
+    }
+
+    return { url, ok: true, status: res.status };
+  } catch (err) {
+    return { url, ok: false, reason: err instanceof Error ? err.message : "unknown error" };
+  }
+}
+
+export interface LinkCheck {
+  url: string;
+  source: "website" | "readme" | "docs" | "badge";
+  ok: boolean;
+  status?: number;
+  reason?: string;
+}
+
+export interface ProjectLinkChecks {
+  owner: string;
+  repository: string;
+  checkedAt: string;
+  broken: number;
+  links: LinkCheck[];
+}
+
+export interface CheckLinksOptions {
+  /**
+   * The maximum number of hosts that are checked at the same time.
+   * @default 5
+   */
+  concurrency?: number;
+
+  /**
+   * The delay in milliseconds between two requests to the same host.
+   * @default 500
+   */
+  hostDelay?: number;
+}
+
+const LINK_CHECKS_PREFIX = "link-checks";
+
+/**
+ * Extracts every outbound link from a markdown document, badges excluded.
+ *
+ * @param {string} markdown - The markdown document, usually a README.
+ * @returns {string[]} The unique absolute http(s) urls in the order they appear.
+ */
+export function extractLinks(markdown: string): string[] {
+  const tree = remark().parse(markdown) as Root;
+  const urls = new Set<string>();
+
+  visit(tree, ["link", "definition"], (node) => {
+    if (
+      (node.type === "link" || node.type === "definition")
+      && /^https?:\/\//.test(node.url)
+      && !isBadgeUrl(node.url)
+    ) {
+      urls.add(node.url);
+    }
+  });
+
+  return [...urls];
+}
+
+/**
+ * Checks whether a link resolves, using a `HEAD` request with a `GET` fallback
+ * for servers that don't support `HEAD`.
+ *
+ * @param {string} url - The url to check.
+ * @returns {Promise<Omit<LinkCheck, "source">>} A promise that resolves to the result of the check.
+ */
+export async function checkLink(url: string): Promise<Omit<LinkCheck, "source">> {
+  try {
+    let res = await fetch(url, {
+      method: "HEAD",
+      redirect: "follow",
+      signal: AbortSignal.timeout(10_000),
+    });
+
+    if (res.status === 405 || res.status === 403 || res.status === 501) {
+      res = await fetch(url, {
+        method: "GET",
+        redirect: "follow",
+        signal: AbortSignal.timeout(10_000),
+      });
+    }
+
+    if (!res.ok) {
+      return { url, ok: false, status: res.status, reason: `link responded with ${res.status}` };
+    }
+
+    return { url, ok: true, status: res.status };
+  } catch (err) {
+    return { url, ok: false, reason: err instanceof Error ? err.message : "unknown error" };
+  }
+}
+
+/**
+ * Checks a list of links. Links are grouped by host, every host is checked
+ * sequentially with a delay between requests, while multiple hosts are checked concurrently.
+ *
+ * @param {Pick<LinkCheck, "url" | "source">[]} links - The links to check.
+ * @param {CheckLinksOptions} [options] - Options for limiting the request rate.
+ * @returns {Promise<LinkCheck[]>} A promise that resolves to the results in the same order as the input.
+ */
+export async function checkLinks(
+  links: Pick<LinkCheck, "url" | "source">[],
+  options: CheckLinksOptions = {},
+): Promise<LinkCheck[]> {
+  const { concurrency = 5, hostDelay = 500 } = options;
+
+  const hosts = new Map<string, number[]>();
+  links.forEach((link, index) => {
+    let host: string;
+    try {
+      host = new URL(link.url).host;
+    } catch {
+      host = link.url;
+    }
+
+    hosts.set(host, [...(hosts.get(host) || []), index]);
+  });
+
+  const results: LinkCheck[] = Array.from({ length: links.length });
+  const queue = [...hosts.values()];
+
+  async function worker() {
+    let indices: number[] | undefined;
+    // eslint-disable-next-line no-cond-assign
+    while ((indices = queue.shift())) {
+      for (const [position, index] of indices.entries()) {
+        if (position > 0) {
+          await new Promise((resolve) => setTimeout(resolve, hostDelay));
+        }
+
+        const link = links[index];
+        const result = link.source === "badge"
+          ? await checkBadge(link.url)
+          : await checkLink(link.url);
+
+        results[index] = { ...result, source: link.source };
+      }
+    }
+  }
+
+  await Promise.all(Array.from({ length: Math.min(concurrency, queue.length) }, worker));
+
+  return results;
+}
+
+/**
+ * Checks the website, README and docs links of a project and stores the result.
+ * Projects without a config, or that are ignored, are not checked.
+ * A config that fails to resolve throws, so the failure is recorded and the project is retried.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<ProjectLinkChecks | undefined>} A promise that resolves to the stored link checks, or `undefined` if the project is not checked.
+ */
+export async function checkProjectLinks(
+  owner: string,
+  repository: string,
+): Promise<ProjectLinkChecks | undefined> {
+  const resolvedMosaicConfig = await resolveMosaicConfig(owner, repository);
+
+  if (resolvedMosaicConfig.type === "error") {
+    throw new Error(resolvedMosaicConfig.message);
+  }
+
+  if (resolvedMosaicConfig.type !== "resolved" || resolvedMosaicConfig.content.project.ignore) {
+    return undefined;
+  }
+
+  const config = resolvedMosaicConfig.content;
+  const links: Pick<LinkCheck, "url" | "source">[] = [];
+  const seen = new Set<string>();
+
+  function addLink(url: string, source: LinkCheck["source"]) {
+    if (!seen.has(url)) {
+      seen.add(url);
+      links.push({ url, source });
+    }
+  }
+
+  if (config.website?.enabled && config.website.url) {
+    addLink(config.website.url, "website");
+  }
+
+  const readmeConfig = config.readme?.enabled ? config.readme : undefined;
+  const documents: { path?: string; source: "readme" | "docs" }[] = [
+    { path: readmeConfig?.path, source: "readme" },
+    ...getDocPages(readmeConfig).map((page) => ({ path: page.path, source: "docs" as const })),
+  ];
+
+  for (const document of documents) {
+    const readme = await getREADME({ owner, repository, readmePath: document.path });
+
+    if (readme.type === "resolved") {
+      extractLinks(readme.content).forEach((url) => addLink(url, document.source));
+      extractBadgeUrls(readme.content).forEach((url) => addLink(url, "badge"));
+    }
+  }
+
+  const results = await checkLinks(links);
+
+  const checks: ProjectLinkChecks = {
+    owner,
+    repository,
+    checkedAt: new Date().toISOString(),
+    broken: results.filter((result) => !result.ok).length,
+    links: results,
+  };
+
+  await useStorage("mosaic").setItem(`${LINK_CHECKS_PREFIX}:${owner}:${repository}`, checks);
+
+  return checks;
+}
+
+/**
+ * Retrieves the stored link checks of a project.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<ProjectLinkChecks | null>} A promise that resolves to the stored link checks, or `null` if the project has never been checked.
+ */
+export async function getProjectLinkChecks(
+  owner: string,
+  repository: string,
+): Promise<ProjectLinkChecks | null> {
+  return await useStorage("mosaic").getItem<ProjectLinkChecks>(`${LINK_CHECKS_PREFIX}:${owner}:${repository}`);
+}
@@ -0,0 +1,52 @@
+import type { ParseConfigResult } from "~/core/config";
+import { parseMosaicConfig } from "~/core/config";
+
+export interface ConfigTaskPayload {
+  /**
+   * A local `mosaic.toml`, or a repository checkout containing `.github/mosaic.toml`.
+   */
+  path?: string;
+  /**
+   * A repository to resolve from GitHub, as `owner/name`.
+   */
+  repository?: string;
+}
+
+/**
+ * Reads and parses a local `mosaic.toml`, so a config can be checked before it is pushed.
+ *
+ * @param {string} path - The path of the file, or of a repository checkout.
+ * @returns {Promise<ParseConfigResult & { path: string }>} A promise that resolves to the parsed config, or the reason it could not be read.
+ */
+export async function parseLocalConfig(path: string): Promise<ParseConfigResult & { path: string }> {
+  // only available when running the tasks locally, through `nitro task run`.
+  const { readFile, stat } = await import("node:fs/promises");
+  const { join, resolve } = await import("node:path");
+
+  let file = resolve(path);
+
+  try {
+    if ((await stat(file)).isDirectory()) {
+      file = join(file, ".github", "mosaic.toml");
+    }
+
+    return {
+      ...await parseMosaicConfig(await readFile(file, "utf-8")),
+      path: file,
+    };
+  } catch (err) {
+    const code = err && typeof err === "object" && "code" in err ? err.code : undefined;
+
+    return {
+      success: false,
+      message: code === "ENOENT" ? `no config found at ${file}` : `could not read config at ${file}`,
+      details: err instanceof Error ? err.message : err,
+      path: file,
+    };
+  }
+}
+
+export function parseRepositorySlug(repository: string | undefined): [owner: string, name: string] | undefined {
+  const [owner, name] = repository?.split("/") ?? [];
+  return owner && name ? [owner, name] : undefined;
+}
@@ -0,0 +1,74 @@
+export interface OpenAPITag {
+  name: string;
+  description: string;
+  externalDocs?: {
+    description?: string;
+    url: string;
+  };
+}
+
+/**
+ * Every tag used in a `defineRouteMeta` block must be registered here,
+ * so the docs group and describe endpoints consistently.
+ */
+export const OPENAPI_TAGS: OpenAPITag[] = [
+  {
+    name: "projects",
+    description: "Projects resolved from a repository and its `mosaic.toml`.",
+    externalDocs: {
+      description: "mosaic.toml reference",
+      url: "https://mosaic.luxass.dev/schemas/mosaic",
+    },
+  },
+  {
+    name: "readme",
+    description: "READMEs and documentation pages of projects.",
+  },
+  {
+    name: "events",
+    description: "Recent GitHub activity of the configured user.",
+  },
+  {
+    name: "profile",
+    description: "The GitHub profile and contributions of a user.",
+  },
+  {
+    name: "assets",
+    description: "Proxied assets, such as README images.",
+  },
+  {
+    name: "schema",
+    description: "The JSON schema of `mosaic.toml`.",
+  },
+  {
+    name: "admin",
+    description: "Operational routes, requiring the admin token.",
+  },
+  {
+    name: "meta",
+    description: "Health checks and other routes about the service itself.",
+  },
+];
+
+/**
+ * Lists the operations that use a tag which is not registered in `OPENAPI_TAGS`.
+ *
+ * @param {Record<string, Record<string, { tags?: string[] }>>} paths - The paths of the spec.
+ * @returns {string[]} The offending operations, e.g. `GET /api/v1/foo (foo)`.
+ */
+export function findUnregisteredTags(paths: Record<string, Record<string, { tags?: string[] }>>): string[] {
+  const registered = new Set(OPENAPI_TAGS.map((tag) => tag.name));
+  const unregistered: string[] = [];
+
+  for (const [path, methods] of Object.entries(paths)) {
+    for (const [method, operation] of Object.entries(methods)) {
+      const unknown = (operation.tags || []).filter((tag) => !registered.has(tag));
+
+      if (!operation.tags?.length || unknown.length) {
+        unregistered.push(`${method.toUpperCase()} ${path} (${unknown.join(", ") || "untagged"})`);
+      }
+    }
+  }
+
+  return unregistered;
+}
@@ -0,0 +1,242 @@
+import { minimatch } from "minimatch";
+
+export interface OwnerConfig {
+  login: string;
+  type: "user" | "organization";
+  /**
+   * Globs matched against the repository name, e.g. `vscode-*`. Every repository is included by default.
+   */
+  include?: string[];
+  /**
+   * Globs matched against the repository name, excluded even when included.
+   */
+  exclude?: string[];
+  /**
+   * A token to access the repositories of the owner, falls back to `github.token`.
+   */
+  token?: string;
+  /**
+   * The site showcasing the projects of the owner, e.g. `https://luxass.dev`.
+   */
+  siteUrl?: string;
+}
+
+export interface OwnerRepository {
+  owner: string;
+  ownerType: OwnerConfig["type"];
+  name: string;
+  nameWithOwner: string;
+  description: string | null;
+  url: string;
+  isFork: boolean;
+  isArchived: boolean;
+  pushedAt: string | null;
+  stars: number;
+  forks: number;
+  openIssues: number;
+  /**
+   * The SPDX id of the license detected by GitHub.
+   */
+  license: string | null;
+}
+
+export interface SkippedRepository {
+  nameWithOwner: string;
+  reason: string;
+}
+
+export interface SettledRepositories<R> {
+  results: R[];
+  /**
+   * The repositories GitHub refused, e.g. when rate limited.
+   */
+  skipped: SkippedRepository[];
+}
+
+export interface SyncRuleMatch {
+  included: boolean;
+  /**
+   * The last rule matching the repository, `undefined` when no rule matched.
+   */
+  rule?: string;
+}
+
+export function getOwners(): OwnerConfig[] {
+  return useRuntimeConfig().owners as OwnerConfig[];
+}
+
+export function getOwner(login: string): OwnerConfig | undefined {
+  return getOwners().find((config) => config.login.toLowerCase() === login.toLowerCase());
+}
+
+/**
+ * Retrieves the token to access the repositories of an owner.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @returns {string | undefined} The token of the owner, or `undefined` to use `github.token`.
+ */
+export function getOwnerToken(owner: string): string | undefined {
+  return getOwner(owner)?.token;
+}
+
+/**
+ * Whether repositories of the owner have their config in the repository itself,
+ * instead of in the luxass/luxass repository.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @returns {boolean} `true` if the owner is configured.
+ */
+export function isConfiguredOwner(owner: string): boolean {
+  return getOwners().some((config) => config.login.toLowerCase() === owner.toLowerCase());
+}
+
+export function matchesOwnerPatterns(owner: OwnerConfig, name: string): boolean {
+  const included = !owner.include?.length || owner.include.some((pattern) => minimatch(name, pattern, { nocase: true }));
+  const excluded = owner.exclude?.some((pattern) => minimatch(name, pattern, { nocase: true })) ?? false;
+
+  return included && !excluded;
+}
+
+export function getSyncRules(): string[] {
+  return useRuntimeConfig().syncRules as string[];
+}
+
+/**
+ * Evaluates the sync rules against a repository, like a `.gitignore`:
+ * rules are globs matched against `owner/name`, a leading `!` excludes instead of includes,
+ * and the last matching rule wins.
+ *
+ * When every rule is an exclusion, repositories are included unless excluded,
+ * otherwise a repository has to be included by a rule.
+ *
+ * @param {string[]} rules - The rules, e.g. `["luxass/*", "!luxass/dotfiles*", "!*-archive"]`.
+ * @param {string} nameWithOwner - The repository, e.g. `luxass/mosaic`.
+ * @returns {SyncRuleMatch} Whether the repository is included, and the rule that decided it.
+ */
+export function evaluateSyncRules(rules: string[], nameWithOwner: string): SyncRuleMatch {
+  let match: SyncRuleMatch = { included: rules.every((rule) => rule.startsWith("!")) };
+
+  for (const rule of rules) {
+    const negated = rule.startsWith("!");
+    const pattern = negated ? rule.slice(1) : rule;
+
+    // patterns without an owner, like `*-archive`, match the repository name of every owner.
+    const target = pattern.includes("/") ? nameWithOwner : nameWithOwner.split("/")[1];
+
+    if (minimatch(target, pattern, { nocase: true })) {
+      match = { included: !negated, rule };
+    }
+  }
+
+  return match;
+}
+
+async function listRepositories(owner: OwnerConfig): Promise<OwnerRepository[]> {
+  const repositories: OwnerRepository[] = [];
+
+  const base = owner.type === "organization"
+    ? `https://api.github.com/orgs/${owner.login}/repos?type=public`
+    : `https://api.github.com/users/${owner.login}/repos?type=owner`;
+
+  for (let page = 1; ; page++) {
+    const url = `${base}&per_page=100&page=${page}`;
+    const data = await withSpan("github.owner_repositories", { "github.owner": owner.login, "page": page }, () => githubFetch(url, { token: owner.token }));
+
+    if (!Array.isArray(data)) {
+      throw new GitHubError("deserialize", `invalid response from github for ${owner.login}`, { url });
+    }
+
+    for (const repository of data) {
+      if (repository.private) {
+        continue;
+      }
+
+      repositories.push({
+        owner: repository.owner.login,
+        ownerType: owner.type,
+        name: repository.name,
+        nameWithOwner: repository.full_name,
+        description: repository.description,
+        url: repository.html_url,
+        isFork: repository.fork,
+        isArchived: repository.archived,
+        pushedAt: repository.pushed_at,
+        stars: repository.stargazers_count,
+        forks: repository.forks_count,
+        openIssues: repository.open_issues_count,
+        license: repository.license?.spdx_id && repository.license.spdx_id !== "NOASSERTION" ? repository.license.spdx_id : null,
+      });
+    }
+
+    if (data.length < 100) {
+      break;
+    }
+  }
+
+  return repositories;
+}
+
+/**
+ * Enumerates the public, non-fork repositories of every configured owner,
+ * filtered by the include and exclude patterns of the owner, but not by the sync rules.
+ *
+ * @param {OwnerConfig[]} [owners] - The owners to enumerate, every configured owner by default.
+ * @returns {Promise<OwnerRepository[]>} A promise that resolves to the repositories, ordered by stars.
+ */
+export async function listOwnerRepositories(owners: OwnerConfig[] = getOwners()): Promise<OwnerRepository[]> {
+  const repositories = await Promise.all(owners.map(async (owner) => {
+    const repositories = await listRepositories(owner);
+    return repositories.filter((repository) => !repository.isFork && matchesOwnerPatterns(owner, repository.name));
+  }));
+
+  return repositories.flat().sort((a, b) => b.stars - a.stars);
+}
+
+/**
+ * Enumerates the repositories of every configured owner that are included by the sync rules.
+ *
+ * @param {OwnerConfig[]} [owners] - The owners to enumerate, every configured owner by default.
+ * @returns {Promise<OwnerRepository[]>} A promise that resolves to the repositories, ordered by stars.
+ */
+export async function getOwnerRepositories(owners?: OwnerConfig[]): Promise<OwnerRepository[]> {
+  const rules = getSyncRules();
+  const repositories = await listOwnerRepositories(owners);
+
+  return repositories.filter((repository) => evaluateSyncRules(rules, repository.nameWithOwner).included);
+}
+
+/**
+ * Maps over repositories with at most `limit` promises running at the same time, like `mapWithConcurrency`.
+ * A single repository GitHub refuses, e.g. when rate limited, is skipped instead of failing every other repository.
+ *
+ * @param {OwnerRepository[]} repositories - The repositories to map over.
+ * @param {number} limit - The maximum number of concurrent calls to `fn`.
+ * @param {(repository: OwnerRepository) => Promise<R | undefined>} fn - The mapper, `undefined` results are left out.
+ * @returns {Promise<SettledRepositories<R>>} A promise that resolves to the results in the same order as `repositories`, and the skipped repositories.
+ */
+export async function settleRepositories<R>(
+  repositories: OwnerRepository[],
+  limit: number,
+  fn: (repository: OwnerRepository) => Promise<R | undefined>,
+): Promise<SettledRepositories<R>> {
+  const skipped: SkippedRepository[] = [];
+
+  const results = await mapWithConcurrency(repositories, limit, async (repository) => {
+    try {
+      return await fn(repository);
+    } catch (err) {
+      if (err instanceof GitHubError) {
+        console.warn(`skipping ${repository.nameWithOwner}: ${err.message}`);
+        skipped.push({ nameWithOwner: repository.nameWithOwner, reason: err.message });
+        return undefined;
+      }
+
+      throw err;
+    }
+  });
+
+  return {
+    results: results.filter((result): result is R => result !== undefined),
+    skipped,
+  };
+}
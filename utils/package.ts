@@ -1,3 +1,4 @@
+import { parse as parseToml } from "smol-toml";
 import { z } from "zod";
 import { base64ToString } from "./base64";
 
@@ -20,6 +21,8 @@ export const PACKAGE_JSON_SCHEMA = z.object({
       "Allows packages within a directory to depend on one another using direct linking of local files. Additionally, dependencies within a workspace are hoisted to the workspace root when possible to reduce duplication. Note: It's also a good idea to set \"private\" to true when using this feature.",
     )
     .optional(),
+  dependencies: z.record(z.string()).describe("Dependencies are specified with a simple hash of package name to version range.").optional(),
+  devDependencies: z.record(z.string()).describe("Dependencies only needed for development and testing.").optional(),
 });
 /**
  * Retrieves the package.json file from a GitHub repository.
@@ -37,15 +40,7 @@ export async function getPackage(
 ): Promise<z.infer<typeof PACKAGE_JSON_SCHEMA>> {
   if (!path.endsWith("/package.json") && path !== "package.json") path += "/package.json";
 
-  const runtimeConfig = useRuntimeConfig();
-
-  const pkgResult = await fetch(`https://api.github.com/repos/${owner}/${repository}/contents/${path}`, {
-    headers: {
-      "Authorization": `Bearer ${runtimeConfig.github.token}`,
-      "Content-Type": "application/vnd.github+json",
-      "X-GitHub-Api-Version": "2022-11-28",
-    },
-  }).then((res) => res.json());
+  const pkgResult = await withSpan("github.package", { "github.repository": `${owner}/${repository}`, "github.path": path }, () => githubFetch(`/repos/${owner}/${repository}/contents/${path}`, { token: getOwnerToken(owner) }));
 
   if (
     !pkgResult
@@ -58,3 +53,124 @@ export async function getPackage(
 
   return await PACKAGE_JSON_SCHEMA.parseAsync(JSON.parse(base64ToString(pkgResult.content)));
 }
+
+export const CARGO_TOML_SCHEMA = z.object({
+  "package": z
+    .object({
+      name: z.string().describe("The name of the crate."),
+      // `version.workspace = true` inherits the version from `[workspace.package]`
+      version: z.union([z.string(), z.object({ workspace: z.literal(true) })]).describe("The version of the crate.").optional(),
+      publish: z.union([z.boolean(), z.array(z.string())]).describe("Which registries the crate can be published to.").optional(),
+    })
+    .optional(),
+  "workspace": z
+    .object({
+      members: z.array(z.string()).optional(),
+      package: z
+        .object({
+          version: z.string().optional(),
+        })
+        .optional(),
+    })
+    .optional(),
+  // dependencies can be a version string or a table, e.g. `{ version = "1", features = ["derive"] }`
+  "dependencies": z.record(z.unknown()).optional(),
+  "dev-dependencies": z.record(z.unknown()).optional(),
+});
+
+/**
+ * Retrieves the Cargo.toml file from a GitHub repository.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @param {string} [path] - The path to the Cargo.toml file.
+ * @returns {Promise<z.infer<typeof CARGO_TOML_SCHEMA> | undefined>} A promise that resolves to the parsed Cargo.toml object, or `undefined` if the repository has none.
+ */
+export async function getCargoManifest(
+  owner: string,
+  repository: string,
+  path: string = "Cargo.toml",
+): Promise<z.infer<typeof CARGO_TOML_SCHEMA> | undefined> {
+  if (!path.endsWith("/Cargo.toml") && path !== "Cargo.toml") path += "/Cargo.toml";
+
+  const manifestResult = await githubFetchOptional(`/repos/${owner}/${repository}/contents/${path}`, { token: getOwnerToken(owner) });
+
+  if (
+    !manifestResult
+    || typeof manifestResult !== "object"
+    || !("content" in manifestResult)
+    || typeof manifestResult.content !== "string"
+  ) {
+    return undefined;
+  }
+
+  return await CARGO_TOML_SCHEMA.parseAsync(parseToml(base64ToString(manifestResult.content)));
+}
+
+/**
+ * Resolves the version of a crate, following `version.workspace = true` to `[workspace.package]`.
+ *
+ * @param {z.infer<typeof CARGO_TOML_SCHEMA>} manifest - The parsed Cargo.toml.
+ * @returns {string | undefined} The version of the crate, or `undefined` if it has none or inherits it from a workspace root elsewhere.
+ */
+export function getCargoPackageVersion(manifest: z.infer<typeof CARGO_TOML_SCHEMA>): string | undefined {
+  const version = manifest.package?.version;
+
+  if (typeof version === "string") {
+    return version;
+  }
+
+  return version?.workspace ? manifest.workspace?.package?.version : undefined;
+}
+
+export const PYPROJECT_TOML_SCHEMA = z.object({
+  "project": z
+    .object({
+      "name": z.string().describe("The name of the project.").optional(),
+      // PEP 508 requirements, e.g. `fastapi>=0.110`
+      "dependencies": z.array(z.string()).optional(),
+      "optional-dependencies": z.record(z.array(z.string())).optional(),
+    })
+    .optional(),
+  // PEP 735 dependency groups, used by uv for dev dependencies.
+  "dependency-groups": z.record(z.array(z.unknown())).optional(),
+  "tool": z
+    .object({
+      poetry: z
+        .object({
+          dependencies: z.record(z.unknown()).optional(),
+          group: z.record(z.object({ dependencies: z.record(z.unknown()).optional() })).optional(),
+        })
+        .optional(),
+    })
+    .optional(),
+});
+
+/**
+ * Retrieves the pyproject.toml file from a GitHub repository.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @param {string} [path] - The path to the pyproject.toml file.
+ * @returns {Promise<z.infer<typeof PYPROJECT_TOML_SCHEMA> | undefined>} A promise that resolves to the parsed pyproject.toml object, or `undefined` if the repository has none.
+ */
+export async function getPyprojectManifest(
+  owner: string,
+  repository: string,
+  path: string = "pyproject.toml",
+): Promise<z.infer<typeof PYPROJECT_TOML_SCHEMA> | undefined> {
+  if (!path.endsWith("/pyproject.toml") && path !== "pyproject.toml") path += "/pyproject.toml";
+
+  const manifestResult = await githubFetchOptional(`/repos/${owner}/${repository}/contents/${path}`, { token: getOwnerToken(owner) });
+
+  if (
+    !manifestResult
+    || typeof manifestResult !== "object"
+    || !("content" in manifestResult)
+    || typeof manifestResult.content !== "string"
+  ) {
+    return undefined;
+  }
+
+  return await PYPROJECT_TOML_SCHEMA.parseAsync(parseToml(base64ToString(manifestResult.content)));
+}
@@ -0,0 +1,113 @@
+import type { H3Event } from "h3";
+
+export interface PageOptions {
+  /**
+   * The last key of the previous page.
+   */
+  cursor?: string;
+  limit: number;
+}
+
+export interface Page<T> {
+  items: T[];
+  /**
+   * Pass as `?cursor=` to get the next page, `null` on the last page.
+   */
+  nextCursor: string | null;
+}
+
+/**
+ * Reads `?limit=` and `?cursor=`, rejecting limits above `pagination.maxPageSize`.
+ *
+ * @param {H3Event} event - The request event.
+ * @returns {PageOptions} The page to load.
+ */
+export function getPageOptions(event: H3Event): PageOptions {
+  const { pagination } = useRuntimeConfig();
+  const { cursor, limit } = getQuery(event);
+
+  const pageSize = limit == null ? pagination.defaultPageSize : Number(limit);
+
+  if (!Number.isInteger(pageSize) || pageSize < 1 || pageSize > pagination.maxPageSize) {
+    throw createApiError({
+      status: 400,
+      code: "invalid_request",
+      message: `invalid limit, expected a number between 1 and ${pagination.maxPageSize}`,
+    });
+  }
+
+  return {
+    cursor: typeof cursor === "string" && cursor ? cursor : undefined,
+    limit: pageSize,
+  };
+}
+
+/**
+ * Loads a page of items stored under a prefix in reverse key order, without loading the items of other pages.
+ * Keys starting with a timestamp are therefore listed newest first.
+ *
+ * @param {string} prefix - The storage prefix, e.g. `inbox`.
+ * @param {PageOptions} options - The page to load.
+ * @param {(item: T) => boolean} [filter] - Skips items that do not match, keeps loading until the page is full.
+ * @returns {Promise<Page<T>>} A promise that resolves to the page.
+ */
+export async function paginateStorage<T>(
+  prefix: string,
+  options: PageOptions,
+  filter?: (item: T) => boolean,
+): Promise<Page<T>> {
+  const storage = useStorage("mosaic");
+
+  let keys = (await storage.getKeys(prefix)).sort().reverse();
+
+  if (options.cursor) {
+    keys = keys.filter((key) => key < options.cursor!);
+  }
+
+  const items: T[] = [];
+  let lastKey: string | undefined;
+
+  for (let offset = 0; offset < keys.length && items.length < options.limit; offset += options.limit) {
+    const batch = keys.slice(offset, offset + options.limit);
+    const values = await Promise.all(batch.map((key) => storage.getItem<T>(key)));
+
+    for (const [index, value] of values.entries()) {
+      if (items.length >= options.limit) {
+        break;
+      }
+
+      lastKey = batch[index];
+
+      if (value != null && (!filter || filter(value))) {
+        items.push(value);
+      }
+    }
+  }
+
+  return {
+    items,
+    nextCursor: lastKey && lastKey !== keys.at(-1) ? lastKey : null,
+  };
+}
+
+/**
+ * Reads every item stored under a prefix in batches, so only one batch is held in memory at a time.
+ *
+ * @param {string} prefix - The storage prefix, e.g. `project-stats`.
+ * @param {number} [batchSize] - The number of items to load at once.
+ * @returns {AsyncGenerator<T>} The stored items.
+ */
+export async function* iterateStorage<T>(prefix: string, batchSize: number = 50): AsyncGenerator<T> {
+  const storage = useStorage("mosaic");
+  const keys = await storage.getKeys(prefix);
+
+  for (let offset = 0; offset < keys.length; offset += batchSize) {
+    const values = await Promise.all(keys.slice(offset, offset + batchSize).map((key) => storage.getItem<T>(key)));
+
+    for (const value of values) {
+      if (value != null) {
+        yield value;
+      }
+    }
+  }
+}
@@ -0,0 +1,47 @@
+export class StageTimeoutError extends Error {
+  constructor(public stage: string, public timeout: number) {
+    super(`stage "${stage}" timed out after ${timeout}ms`);
+    this.name = "StageTimeoutError";
+  }
+}
+
+export interface StageOptions {
+  /**
+   * The maximum time in milliseconds the stage is allowed to run.
+   */
+  timeout: number;
+
+  attributes?: Span["attributes"];
+}
+
+/**
+ * Runs a single stage of a pipeline inside of its own span, bounded by a timeout.
+ * The stage receives a signal that is aborted on timeout, which should be passed on to any `fetch` it makes.
+ *
+ * @param {string} stage - The name of the stage, e.g. `config.fetch`.
+ * @param {StageOptions} options - The timeout and span attributes of the stage.
+ * @param {(signal: AbortSignal) => Promise<T>} fn - The stage itself.
+ * @returns {Promise<T>} The result of the stage.
+ * @throws {StageTimeoutError} If the stage does not finish in time.
+ */
+export async function runStage<T>(
+  stage: string,
+  options: StageOptions,
+  fn: (signal: AbortSignal) => Promise<T>,
+): Promise<T> {
+  const signal = AbortSignal.timeout(options.timeout);
+
+  return withSpan(stage, { "pipeline.stage": stage, ...options.attributes }, () => {
+    return new Promise<T>((resolve, reject) => {
+      const onAbort = () => {
+        reject(new StageTimeoutError(stage, options.timeout));
+      };
+
+      signal.addEventListener("abort", onAbort, { once: true });
+
+      fn(signal)
+        .then(resolve, (err) => reject(signal.aborted ? new StageTimeoutError(stage, options.timeout) : err))
+        .finally(() => signal.removeEventListener("abort", onAbort));
+    });
+  });
+}
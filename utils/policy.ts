@@ -0,0 +1,145 @@
+import { graphql } from "@octokit/graphql";
+import { gql } from "github-schema";
+
+/**
+ * A policy check, in the same shape as the items of the onboarding checklist.
+ */
+export interface PolicyCheck {
+  id: "license" | "code-of-conduct" | "security-policy" | "branch-protection";
+  label: string;
+  done: boolean;
+  /**
+   * Where the check can be fixed, or the policy can be found when the check is done.
+   */
+  fix: string;
+}
+
+export interface PolicyReport {
+  repository: string;
+  checks: PolicyCheck[];
+}
+
+const REPOSITORY_POLICY_QUERY = gql`
+  #graphql
+  query getRepositoryPolicy($owner: String!, $name: String!) {
+    repository(owner: $owner, name: $name) {
+      nameWithOwner
+      url
+      licenseInfo {
+        spdxId
+        url
+      }
+      codeOfConduct {
+        name
+        url
+      }
+      isSecurityPolicyEnabled
+      securityPolicyUrl
+      defaultBranchRef {
+        name
+        branchProtectionRule {
+          id
+        }
+      }
+    }
+  }
+`;
+
+interface RepositoryPolicyResult {
+  repository: {
+    nameWithOwner: string;
+    url: string;
+    licenseInfo: { spdxId: string | null; url: string | null } | null;
+    codeOfConduct: { name: string; url: string | null } | null;
+    isSecurityPolicyEnabled: boolean | null;
+    securityPolicyUrl: string | null;
+    defaultBranchRef: {
+      name: string;
+      branchProtectionRule: { id: string } | null;
+    } | null;
+  } | null;
+}
+
+// branches can also be protected by rulesets, which are not exposed by the branch protection rule.
+async function hasBranchRuleset(owner: string, repository: string, branch: string): Promise<boolean> {
+  const rules = await githubFetchOptional(`/repos/${owner}/${repository}/rules/branches/${branch}`, { token: getOwnerToken(owner) });
+  return Array.isArray(rules) && rules.length > 0;
+}
+
+/**
+ * Checks a repository for a license, code of conduct, security policy and a protected default branch.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<PolicyReport | undefined>} A promise that resolves to the report, or `undefined` if the repository could not be found.
+ * @throws {GitHubError} An error classifying why the repository could not be fetched, for anything but a missing repository.
+ */
+export async function getRepositoryPolicy(
+  owner: string,
+  repository: string,
+): Promise<PolicyReport | undefined> {
+  if (!owner || !repository) {
+    return undefined;
+  }
+
+  const runtimeConfig = useRuntimeConfig();
+
+  const { repository: repo } = await withSpan("github.policy", { "github.repository": `${owner}/${repository}` }, () => graphql<RepositoryPolicyResult>(REPOSITORY_POLICY_QUERY, {
+    headers: {
+      "Authorization": `Bearer ${getOwnerToken(owner) || runtimeConfig.github.token}`,
+      "Content-Type": "application/vnd.github+json",
+      "X-GitHub-Api-Version": "2022-11-28",
+    },
+    name: repository,
+    owner,
+  }).catch((err) => {
+    const githubError = fromGraphqlError(err);
+
+    // a missing repository is expected, the caller decides how to report it.
+    if (githubError?.kind === "not_found") {
+      return { repository: null };
+    }
+
+    throw githubError ?? err;
+  }));
+
+  if (!repo) {
+    return undefined;
+  }
+
+  const branch = repo.defaultBranchRef?.name;
+  const branchProtected = !!repo.defaultBranchRef?.branchProtectionRule
+    || (branch ? await hasBranchRuleset(owner, repository, branch) : false);
+
+  const checks: PolicyCheck[] = [
+    {
+      id: "license",
+      label: "has a LICENSE",
+      done: !!repo.licenseInfo,
+      fix: repo.licenseInfo?.url || `${repo.url}/community/license/new`,
+    },
+    {
+      id: "code-of-conduct",
+      label: "has a CODE_OF_CONDUCT",
+      done: !!repo.codeOfConduct,
+      fix: repo.codeOfConduct?.url || `${repo.url}/community/code-of-conduct/new`,
+    },
+    {
+      id: "security-policy",
+      label: "has a SECURITY.md",
+      done: !!repo.isSecurityPolicyEnabled,
+      fix: repo.securityPolicyUrl || `${repo.url}/security/policy`,
+    },
+    {
+      id: "branch-protection",
+      label: "protects the default branch",
+      done: branchProtected,
+      fix: `${repo.url}/settings/branches`,
+    },
+  ];
+
+  return {
+    repository: repo.nameWithOwner,
+    checks,
+  };
+}
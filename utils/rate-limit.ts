@@ -0,0 +1,121 @@
+export interface RateLimitGroup {
+  name: string;
+  /**
+   * A glob matched against the request path, e.g. `/api/v1/mosaic/*\/*\/readme/**`.
+   */
+  pattern: string;
+  /**
+   * The maximum number of requests a client can burst.
+   */
+  capacity: number;
+  /**
+   * The number of requests a client regains every minute.
+   */
+  refillPerMinute: number;
+}
+
+export interface RateLimitResult {
+  allowed: boolean;
+  limit: number;
+  remaining: number;
+  /**
+   * Seconds until the bucket is full again.
+   */
+  reset: number;
+  /**
+   * Seconds until the next request is allowed, only set when the request is not allowed.
+   */
+  retryAfter?: number;
+}
+
+interface Bucket {
+  tokens: number;
+  updatedAt: number;
+  /**
+   * When the bucket is full again, after which it is the same as a missing bucket.
+   */
+  fullAt: number;
+}
+
+export interface TokenBucketLimiterOptions {
+  /**
+   * The maximum number of buckets kept in memory, the least recently used buckets are evicted first.
+   * @default 10_000
+   */
+  maxBuckets?: number;
+
+  /**
+   * How often full buckets are swept, in milliseconds.
+   * @default 60_000
+   */
+  sweepInterval?: number;
+}
+
+/**
+ * An in-memory token bucket rate limiter, keyed by client and route group.
+ *
+ * The buckets live in the memory of a single process. On Cloudflare every isolate has its own buckets,
+ * and isolates are recycled at any time, so the limits are a best effort per isolate rather than global.
+ * Use a Workers rate limiting binding, or WAF rate limiting rules, for limits that must hold globally.
+ */
+export class TokenBucketLimiter {
+  private buckets = new Map<string, Bucket>();
+  private lastSweep = 0;
+  private maxBuckets: number;
+  private sweepInterval: number;
+
+  constructor(options: TokenBucketLimiterOptions = {}) {
+    this.maxBuckets = options.maxBuckets ?? 10_000;
+    this.sweepInterval = options.sweepInterval ?? 60_000;
+  }
+
+  take(key: string, group: RateLimitGroup, now: number = Date.now()): RateLimitResult {
+    const refillPerMs = group.refillPerMinute / 60_000;
+    const bucket = this.buckets.get(key) || { tokens: group.capacity, updatedAt: now, fullAt: now };
+
+    bucket.tokens = Math.min(group.capacity, bucket.tokens + (now - bucket.updatedAt) * refillPerMs);
+    bucket.updatedAt = now;
+
+    const allowed = bucket.tokens >= 1;
+    if (allowed) {
+      bucket.tokens -= 1;
+    }
+
+    bucket.fullAt = now + (group.capacity - bucket.tokens) / refillPerMs;
+
+    // re-inserting moves the bucket to the end, keeping the map ordered from least to most recently used.
+    this.buckets.delete(key);
+    this.buckets.set(key, bucket);
+    this.prune(now);
+
+    return {
+      allowed,
+      limit: group.capacity,
+      remaining: Math.floor(bucket.tokens),
+      reset: Math.ceil((group.capacity - bucket.tokens) / refillPerMs / 1000),
+      retryAfter: allowed ? undefined : Math.ceil((1 - bucket.tokens) / refillPerMs / 1000),
+    };
+  }
+
+  private prune(now: number) {
+    // full buckets can be dropped, a missing bucket starts out full.
+    if (now - this.lastSweep >= this.sweepInterval) {
+      this.lastSweep = now;
+
+      for (const [key, bucket] of this.buckets) {
+        if (bucket.fullAt <= now) {
+          this.buckets.delete(key);
+        }
+      }
+    }
+
+    // a flood of unique clients can outpace the sweep, so the least recently used buckets are evicted.
+    for (const key of this.buckets.keys()) {
+      if (this.buckets.size <= this.maxBuckets) {
+        break;
+      }
+
+      this.buckets.delete(key);
+    }
+  }
+}
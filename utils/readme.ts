@@ -29,7 +29,8 @@ export async function getREADME(
       readmePath = readmePath.slice(1);
     }
 
-    if (!readmePath.endsWith("README.md")) {
+    // paths to markdown files are used as is, e.g. `docs/intro.md`
+    if (!/\.mdx?$/i.test(readmePath)) {
       readmePath += "/README.md";
     }
 
@@ -41,13 +42,13 @@ export async function getREADME(
   const runtimeConfig = useRuntimeConfig();
 
   try {
-    const result = await fetch(readmeUrl.toString(), {
+    const result = await withSpan("github.readme", { "github.repository": `${owner}/${repository}` }, () => fetch(readmeUrl.toString(), {
       headers: {
-        "Authorization": `Bearer ${runtimeConfig.github.token}`,
+        "Authorization": `Bearer ${getOwnerToken(owner) || runtimeConfig.github.token}`,
         "Content-Type": "application/vnd.github+json",
         "X-GitHub-Api-Version": "2022-11-28",
       },
-    }).then((res) => res.json());
+    }).then((res) => res.json()));
 
     if (
       !result
@@ -0,0 +1,127 @@
+import { z } from "zod";
+
+export type Registry = "npm" | "crates";
+
+export interface PackageVersion {
+  version: string;
+  publishedAt: string;
+}
+
+const NPM_PACKUMENT_SCHEMA = z.object({
+  "dist-tags": z.object({
+    latest: z.string(),
+  }),
+  "time": z.record(z.string()),
+});
+
+const CRATES_VERSIONS_SCHEMA = z.object({
+  versions: z.array(
+    z.object({
+      num: z.string(),
+      created_at: z.string(),
+      yanked: z.boolean().optional(),
+    }),
+  ),
+});
+
+/**
+ * Retrieves every published version of a package on npm.
+ *
+ * @param {string} name - The name of the npm package.
+ * @returns {Promise<PackageVersion[] | undefined>} A promise that resolves to the versions sorted from newest to oldest, or `undefined` if the package could not be found.
+ */
+export async function getNpmVersions(name: string): Promise<PackageVersion[] | undefined> {
+  if (!name) {
+    return undefined;
+  }
+
+  const res = await fetch(`https://registry.npmjs.org/${name}`);
+
+  if (!res.ok) {
+    return undefined;
+  }
+
+  const result = await NPM_PACKUMENT_SCHEMA.safeParseAsync(await res.json());
+
+  if (!result.success) {
+    console.error(`invalid npm registry response for ${name}`, result.error);
+    return undefined;
+  }
+
+  // the `time` field also contains `created` and `modified`, which are not versions.
+  const versions = Object.entries(result.data.time)
+    .filter(([version]) => version !== "created" && version !== "modified")
+    .map(([version, publishedAt]) => ({ version, publishedAt }));
+
+  return sortVersions(versions);
+}
+
+/**
+ * Retrieves the version of a package that is tagged as `latest` on npm.
+ * This is what `npm install` resolves to, which is not necessarily the most recently published version.
+ *
+ * @param {string} name - The name of the npm package.
+ * @returns {Promise<string | undefined>} A promise that resolves to the latest version, or `undefined` if the package could not be found.
+ */
+export async function getNpmLatestVersion(name: string): Promise<string | undefined> {
+  if (!name) {
+    return undefined;
+  }
+
+  const res = await fetch(`https://registry.npmjs.org/${name}`);
+
+  if (!res.ok) {
+    return undefined;
+  }
+
+  const result = await NPM_PACKUMENT_SCHEMA.safeParseAsync(await res.json());
+
+  if (!result.success) {
+    console.error(`invalid npm registry response for ${name}`, result.error);
+    return undefined;
+  }
+
+  return result.data["dist-tags"].latest;
+}
+
+/**
+ * Retrieves every published version of a crate on crates.io.
+ *
+ * @param {string} name - The name of the crate.
+ * @returns {Promise<PackageVersion[] | undefined>} A promise that resolves to the versions sorted from newest to oldest, or `undefined` if the crate could not be found.
+ */
+export async function getCrateVersions(name: string): Promise<PackageVersion[] | undefined> {
+  if (!name) {
+    return undefined;
+  }
+
+  const res = await fetch(`https://crates.io/api/v1/crates/${name}/versions`, {
+    headers: {
+      // crates.io requires a user agent to be set.
+      "User-Agent": "mosaic (https://github.com/luxass/mosaic)",
+    },
+  });
+
+  if (!res.ok) {
+    return undefined;
+  }
+
+  const result = await CRATES_VERSIONS_SCHEMA.safeParseAsync(await res.json());
+
+  if (!result.success) {
+    console.error(`invalid crates.io response for ${name}`, result.error);
+    return undefined;
+  }
+
+  const versions = result.data.versions
+    .filter((version) => !version.yanked)
+    .map((version) => ({ version: version.num, publishedAt: version.created_at }));
+
+  return sortVersions(versions);
+}
+
+function sortVersions(versions: PackageVersion[]): PackageVersion[] {
+  return versions.sort(
+    (a, b) => new Date(b.publishedAt).getTime() - new Date(a.publishedAt).getTime(),
+  );
+}
@@ -0,0 +1,168 @@
+export interface ReleaseAsset {
+  name: string;
+  url: string;
+  size: number;
+  downloads: number;
+}
+
+export interface Release {
+  tag: string;
+  url: string;
+  publishedAt: string | null;
+  assets: ReleaseAsset[];
+  downloads: number;
+}
+
+/**
+ * The maximum number of pages of releases to fetch, 100 releases per page.
+ */
+const MAX_RELEASE_PAGES = 10;
+
+/**
+ * Retrieves the releases of a GitHub repository, including the download count of every asset.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<Release[] | undefined>} A promise that resolves to the releases, or `undefined` if the repository does not exist.
+ * @throws {GitHubError} An error classifying why the releases could not be fetched, for anything but a 404.
+ */
+export async function getReleases(
+  owner: string,
+  repository: string,
+): Promise<Release[] | undefined> {
+  if (!owner || !repository) {
+    return undefined;
+  }
+
+  // follow the `Link` header, repositories with more than 100 releases span multiple pages.
+  const data = await githubFetchAll<any>(`/repos/${owner}/${repository}/releases?per_page=100`, {
+    maxPages: MAX_RELEASE_PAGES,
+    token: getOwnerToken(owner),
+  }).catch((err) => {
+    if (err instanceof GitHubError && err.kind === "not_found") {
+      return undefined;
+    }
+
+    throw err;
+  });
+
+  if (!data) {
+    return undefined;
+  }
+
+  return data.map((release: any) => {
+    const assets: ReleaseAsset[] = (release.assets || []).map((asset: any) => ({
+      name: asset.name,
+      url: asset.browser_download_url,
+      size: asset.size,
+      downloads: asset.download_count || 0,
+    }));
+
+    return {
+      tag: release.tag_name,
+      url: release.html_url,
+      publishedAt: release.published_at,
+      assets,
+      downloads: assets.reduce((total, asset) => total + asset.downloads, 0),
+    };
+  });
+}
+
+/**
+ * Sums the download count of every release asset in a GitHub repository.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<number | undefined>} A promise that resolves to the total downloads, or `undefined` if the repository does not exist.
+ */
+export async function getReleaseDownloads(
+  owner: string,
+  repository: string,
+): Promise<number | undefined> {
+  const releases = await getReleases(owner, repository);
+
+  if (!releases) {
+    return undefined;
+  }
+
+  return releases.reduce((total, release) => total + release.downloads, 0);
+}
+
+/**
+ * Parses a tag as a semver version, allowing a `v` or package name prefix (e.g. `v1.0.0` or `pkg@1.0.0`).
+ *
+ * @param {string} tag - The name of the tag.
+ * @returns {[number, number, number, string | undefined] | undefined} The version parts, or `undefined` if the tag is not a semver version.
+ */
+function parseSemverTag(tag: string): [number, number, number, string | undefined] | undefined {
+  const match = tag.match(/^(?:.*@)?v?(\d+)\.(\d+)\.(\d+)(?:-([\w.-]+))?(?:\+[\w.-]+)?$/);
+
+  if (!match) {
+    return undefined;
+  }
+
+  return [Number(match[1]), Number(match[2]), Number(match[3]), match[4]];
+}
+
+/**
+ * Compares two semver tags, sorting the newest version first.
+ */
+export function compareSemverTags(a: string, b: string): number {
+  const left = parseSemverTag(a);
+  const right = parseSemverTag(b);
+
+  if (!left || !right) {
+    return left ? -1 : right ? 1 : 0;
+  }
+
+  for (let i = 0; i < 3; i++) {
+    if (left[i] !== right[i]) {
+      return (right[i] as number) - (left[i] as number);
+    }
+  }
+
+  // a release is newer than its prereleases.
+  if (left[3] === right[3]) return 0;
+  if (!left[3]) return -1;
+  if (!right[3]) return 1;
+  return right[3].localeCompare(left[3], undefined, { numeric: true });
+}
+
+/**
+ * Retrieves the latest git tag of a GitHub repository.
+ * This is the tag of the latest release, or the highest semver tag if the repository has no releases,
+ * since GitHub lists tags in reverse lexicographic order rather than by version or date.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<string | undefined>} A promise that resolves to the tag name, or `undefined` if the repository has no tags.
+ * @throws {GitHubError} An error classifying why the tags could not be fetched, for anything but a 404.
+ */
+export async function getLatestTag(
+  owner: string,
+  repository: string,
+): Promise<string | undefined> {
+  if (!owner || !repository) {
+    return undefined;
+  }
+
+  const latestRelease = await githubFetchOptional<{ tag_name?: unknown }>(`/repos/${owner}/${repository}/releases/latest`, {
+    token: getOwnerToken(owner),
+  });
+
+  if (latestRelease && typeof latestRelease.tag_name === "string") {
+    return latestRelease.tag_name;
+  }
+
+  const data = await githubFetchOptional<{ name?: unknown }[]>(`/repos/${owner}/${repository}/tags?per_page=100`, {
+    token: getOwnerToken(owner),
+  });
+
+  if (!Array.isArray(data)) {
+    return undefined;
+  }
+
+  const tags = data.map((tag) => tag.name).filter((name): name is string => typeof name === "string");
+
+  return tags.sort(compareSemverTags)[0];
+}
@@ -0,0 +1,93 @@
+import type { InboxItem } from "./inbox";
+import { z } from "zod";
+
+export const REPORT_KINDS = ["broken_link", "security", "other"] as const;
+
+export type ReportKind = typeof REPORT_KINDS[number];
+
+export interface Report {
+  kind: ReportKind;
+  message: string;
+  /**
+   * The url the report is about, e.g. the broken link.
+   */
+  url?: string;
+  /**
+   * How the reporter wants to be contacted, never exposed outside the admin api.
+   */
+  contact?: string;
+}
+
+export const REPORT_BODY_SCHEMA = z.object({
+  kind: z.enum(REPORT_KINDS),
+  message: z.string().trim().min(1).max(2000),
+  url: z.string().url().max(2048).optional(),
+  contact: z.string().trim().max(320).optional(),
+  captchaToken: z.string().optional(),
+});
+
+/**
+ * Verifies a Cloudflare Turnstile token. Captchas are optional, so every token is accepted
+ * when no secret is configured.
+ *
+ * @param {string | undefined} token - The token sent by the client.
+ * @param {string} [ip] - The ip of the client.
+ * @returns {Promise<boolean>} A promise that resolves to `true` if the token is valid.
+ */
+export async function verifyCaptcha(token: string | undefined, ip?: string): Promise<boolean> {
+  const { reports } = useRuntimeConfig();
+
+  if (!reports.turnstileSecret) {
+    return true;
+  }
+
+  if (!token) {
+    return false;
+  }
+
+  const res = await fetch("https://challenges.cloudflare.com/turnstile/v0/siteverify", {
+    method: "POST",
+    headers: {
+      "Content-Type": "application/json",
+    },
+    body: JSON.stringify({
+      secret: reports.turnstileSecret,
+      response: token,
+      remoteip: ip,
+    }),
+  });
+
+  if (!res.ok) {
+    return false;
+  }
+
+  const data = await res.json() as { success?: boolean };
+  return data.success === true;
+}
+
+/**
+ * Stores a report in the admin inbox.
+ *
+ * @param {string} owner - The owner of the reported repository.
+ * @param {string} repository - The name of the reported repository.
+ * @param {Report} report - The report.
+ * @returns {Promise<InboxItem>} A promise that resolves to the inbox item.
+ */
+export async function createReport(
+  owner: string,
+  repository: string,
+  report: Report,
+): Promise<InboxItem> {
+  return await addInboxItem({
+    source: "report",
+    title: `${report.kind.replace("_", " ")} reported for ${owner}/${repository}`,
+    owner,
+    repository,
+    data: {
+      kind: report.kind,
+      message: report.message,
+      url: report.url,
+      contact: report.contact,
+    },
+  });
+}
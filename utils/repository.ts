@@ -17,25 +17,12 @@ export async function getRepositoryType(
     return undefined;
   }
 
-  const runtimeConfig = useRuntimeConfig();
+  const data = await githubFetchOptional(`/repos/${owner}/${repository}`, { token: getOwnerToken(owner) });
 
-  const res = await fetch(
-    `https://api.github.com/repos/${owner}/${repository}`,
-    {
-      headers: {
-        "Authorization": `Bearer ${runtimeConfig.github.token}`,
-        "Content-Type": "application/vnd.github+json",
-        "X-GitHub-Api-Version": "2022-11-28",
-      },
-    },
-  );
-
-  if (!res.ok) {
+  if (data === undefined) {
     return;
   }
 
-  const data = await res.json();
-
   if (!data || typeof data !== "object") {
     console.error("Invalid response from GitHub API");
     return;
@@ -66,6 +53,16 @@ const REPOSITORY_QUERY = gql`
       isFork
       isPrivate
       nameWithOwner
+      owner {
+        login
+        ... on Sponsorable {
+          hasSponsorsListing
+        }
+      }
+      fundingLinks {
+        platform
+        url
+      }
       description
       pushedAt
       url
@@ -73,7 +70,21 @@ const REPOSITORY_QUERY = gql`
         name
       }
       stargazerCount
-      languages(first: 1, orderBy: { field: SIZE, direction: DESC }) {
+      openGraphImageUrl
+      usesCustomOpenGraphImage
+      licenseInfo {
+        spdxId
+        name
+        url
+      }
+      repositoryTopics(first: 20) {
+        nodes {
+          topic {
+            name
+          }
+        }
+      }
+      languages(first: 5, orderBy: { field: SIZE, direction: DESC }) {
         nodes {
           name
           color
@@ -93,15 +104,24 @@ export async function getRepository(
 
   const runtimeConfig = useRuntimeConfig();
 
-  const { repository } = await graphql<RepositoryNode>(REPOSITORY_QUERY, {
+  const { repository } = await withSpan("github.repository", { "github.repository": `${owner}/${name}` }, () => graphql<RepositoryNode>(REPOSITORY_QUERY, {
     headers: {
-      "Authorization": `Bearer ${runtimeConfig.github.token}`,
+      "Authorization": `Bearer ${getOwnerToken(owner) || runtimeConfig.github.token}`,
       "Content-Type": "application/vnd.github+json",
       "X-GitHub-Api-Version": "2022-11-28",
     },
     name,
     owner,
-  });
+  }).catch((err) => {
+    const githubError = fromGraphqlError(err);
+
+    // a missing repository is expected, the caller decides how to report it.
+    if (githubError?.kind === "not_found") {
+      return { repository: null };
+    }
+
+    throw githubError ?? err;
+  }));
 
   // to prevent returning null from the query
   if (!repository) {
@@ -131,7 +151,8 @@ export async function* getExternalRepositories(
       for (const item of data) {
         if (item.type === "file") {
           yield item.path;
-        } else if (item.type === "dir") {
+        } else if (item.type === "dir" && item.path !== ".github/mosaic/gists") {
+          // gists are not repositories, they are listed by `getConfiguredGists`
           yield * getExternalRepositories(item.path);
         }
       }
@@ -158,7 +179,7 @@ export async function getRepositoryLanguages(
       `https://api.github.com/repos/${owner}/${repositoryName}/languages`,
       {
         headers: {
-          "Authorization": `Bearer ${runtimeConfig.github.token}`,
+          "Authorization": `Bearer ${getOwnerToken(owner) || runtimeConfig.github.token}`,
           "Content-Type": "application/vnd.github+json",
           "X-GitHub-Api-Version": "2022-11-28",
         },
@@ -0,0 +1,69 @@
+import type { AnyZodObject } from "zod";
+import { z } from "zod";
+import { zodToJsonSchema } from "zod-to-json-schema";
+import { CURRENT_CONFIG_VERSION } from "~/core/migrations";
+
+const SCHEMA_BASE_URL = "https://mosaic.luxass.dev/schemas/mosaic";
+
+interface SchemaVersion {
+  schema: AnyZodObject;
+  examples: Record<string, unknown>[];
+}
+
+/**
+ * The schema of every supported config version, older configs are upgraded by `core/migrations.ts`.
+ * Only the current version follows `MOSAIC_SCHEMA`, older versions are frozen when a new version is introduced.
+ */
+const SCHEMA_VERSIONS: Record<number, SchemaVersion> = {
+  1: {
+    schema: MOSAIC_SCHEMA,
+    examples: [
+      {
+        project: { name: "mosaic", description: "the backend of luxass.dev", version: true, stars: true },
+        readme: { enabled: true, docs: ["docs/configuration.md"] },
+        website: { enabled: true, url: "https://mosaic.luxass.dev" },
+      },
+    ],
+  },
+};
+
+export function getSchemaVersions(): number[] {
+  return Object.keys(SCHEMA_VERSIONS).map(Number).sort((a, b) => a - b);
+}
+
+export function getSchemaUrl(version: number): string {
+  return `${SCHEMA_BASE_URL}/v${version}.json`;
+}
+
+/**
+ * Generates the JSON schema of a config version.
+ *
+ * @param {number} [version] - The config version, defaults to the current version.
+ * @returns {Record<string, unknown> | undefined} The JSON schema, or `undefined` if the version is not supported.
+ */
+export function getMosaicJsonSchema(version: number = CURRENT_CONFIG_VERSION): Record<string, unknown> | undefined {
+  const entry = SCHEMA_VERSIONS[version];
+
+  if (!entry) {
+    return undefined;
+  }
+
+  const jsonSchema = zodToJsonSchema(
+    entry.schema.merge(
+      z.object({
+        $schema: z
+          .string({
+            description: "Ignore this, it's just for editors.",
+          })
+          .default(getSchemaUrl(version)),
+      }),
+    ),
+  );
+
+  return {
+    $id: getSchemaUrl(version),
+    ...jsonSchema,
+    title: `mosaic.toml v${version}`,
+    examples: entry.examples,
+  };
+}
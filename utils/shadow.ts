@@ -0,0 +1,114 @@
+import type { Page, PageOptions } from "./pagination";
+
+export interface ShadowDiff {
+  path: string;
+  requestId?: string;
+  recordedAt: string;
+  status: { production: number; staging?: number };
+  differences: string[];
+  error?: string;
+}
+
+// only these headers are forwarded to staging, everything else (auth, cookies, client ips) is dropped.
+const FORWARDED_HEADERS = ["accept", "accept-language", "x-transform", "x-transform-name", "x-mosaic-repositories"];
+
+// fields that differ on every request and would make every response look different.
+const VOLATILE_KEYS = new Set(["lastModified", "timestamp", "trace_id", "checkedAt", "lastChecked"]);
+
+const SHADOW_DIFFS_PREFIX = "shadow-diffs";
+const MAX_DIFFERENCES = 50;
+
+export function scrubHeaders(headers: Record<string, string | undefined>): Record<string, string> {
+  return Object.fromEntries(
+    Object.entries(headers).filter(
+      (entry): entry is [string, string] => FORWARDED_HEADERS.includes(entry[0].toLowerCase()) && entry[1] != null,
+    ),
+  );
+}
+
+/**
+ * Whether a production response can be mirrored to staging and compared.
+ * Only JSON bodies that are already in memory are mirrored, streams like exports and proxied assets can only be read once.
+ *
+ * @param {string | undefined} contentType - The content type of the production response.
+ * @param {unknown} body - The body of the production response.
+ * @returns {boolean} `true` if the body is a JSON string, or a plain object or array that is serialized as JSON.
+ */
+export function isMirroredResponse(contentType: string | undefined, body: unknown): boolean {
+  const isJson = contentType != null && /^application\/(?:[\w.-]+\+)?json$/i.test(contentType.split(";")[0].trim());
+
+  if (typeof body === "string") {
+    return isJson;
+  }
+
+  if (body == null || typeof body !== "object") {
+    return false;
+  }
+
+  const prototype = Object.getPrototypeOf(body);
+  const isPlain = Array.isArray(body) || prototype === Object.prototype || prototype === null;
+
+  // objects without a content type are serialized as JSON when the response is sent.
+  return isPlain && (contentType == null || isJson);
+}
+
+/**
+ * Lists the JSON paths at which two values differ.
+ *
+ * @param {unknown} a - The production value.
+ * @param {unknown} b - The staging value.
+ * @param {string} [path] - The path of the values, used when recursing.
+ * @param {string[]} [differences] - The differences found so far.
+ * @returns {string[]} The differing paths, e.g. `$.projects[0].stars`. At most 50 paths are returned.
+ */
+export function diffJson(a: unknown, b: unknown, path = "$", differences: string[] = []): string[] {
+  if (differences.length >= MAX_DIFFERENCES) {
+    return differences;
+  }
+
+  if (Array.isArray(a) && Array.isArray(b)) {
+    if (a.length !== b.length) {
+      differences.push(`${path}.length`);
+    }
+
+    for (let i = 0; i < Math.min(a.length, b.length); i++) {
+      diffJson(a[i], b[i], `${path}[${i}]`, differences);
+    }
+
+    return differences;
+  }
+
+  if (a && b && typeof a === "object" && typeof b === "object" && !Array.isArray(a) && !Array.isArray(b)) {
+    const keys = new Set([...Object.keys(a), ...Object.keys(b)]);
+
+    for (const key of keys) {
+      if (VOLATILE_KEYS.has(key)) {
+        continue;
+      }
+
+      diffJson((a as Record<string, unknown>)[key], (b as Record<string, unknown>)[key], `${path}.${key}`, differences);
+    }
+
+    return differences;
+  }
+
+  if (a !== b) {
+    differences.push(path);
+  }
+
+  return differences;
+}
+
+export async function recordShadowDiff(diff: ShadowDiff): Promise<void> {
+  const key = `${SHADOW_DIFFS_PREFIX}:${Date.now()}-${crypto.randomUUID().slice(0, 8)}`;
+  await useStorage("mosaic").setItem(key, diff);
+}
+
+export async function getShadowDiffs(page: PageOptions): Promise<Page<ShadowDiff>> {
+  return await paginateStorage<ShadowDiff>(SHADOW_DIFFS_PREFIX, page);
+}
+
+export async function clearShadowDiffs(): Promise<void> {
+  const storage = useStorage("mosaic");
+  await Promise.all((await storage.getKeys(SHADOW_DIFFS_PREFIX)).map((key) => storage.removeItem(key)));
+}
@@ -0,0 +1,71 @@
+export interface OpenAPISpec {
+  paths?: Record<string, Record<string, unknown>>;
+  components?: {
+    schemas?: Record<string, unknown>;
+  };
+}
+
+export interface SpecDiff {
+  paths: {
+    added: string[];
+    removed: string[];
+    changed: string[];
+  };
+  schemas: {
+    added: string[];
+    removed: string[];
+    changed: string[];
+  };
+}
+
+const SPECS_PREFIX = "openapi-specs";
+
+function diffRecords(from: Record<string, unknown>, to: Record<string, unknown>) {
+  const added = Object.keys(to).filter((key) => !(key in from));
+  const removed = Object.keys(from).filter((key) => !(key in to));
+  const changed = Object.keys(to).filter(
+    (key) => key in from && JSON.stringify(from[key]) !== JSON.stringify(to[key]),
+  );
+
+  return { added, removed, changed };
+}
+
+// paths are compared per operation, so `GET /foo` and `POST /foo` are reported separately.
+function operations(spec: OpenAPISpec): Record<string, unknown> {
+  const result: Record<string, unknown> = {};
+
+  for (const [path, methods] of Object.entries(spec.paths || {})) {
+    for (const [method, operation] of Object.entries(methods)) {
+      result[`${method.toUpperCase()} ${path}`] = operation;
+    }
+  }
+
+  return result;
+}
+
+/**
+ * Computes the added, removed and changed operations and schemas between two specs.
+ *
+ * @param {OpenAPISpec} from - The old spec.
+ * @param {OpenAPISpec} to - The new spec.
+ * @returns {SpecDiff} The diff.
+ */
+export function diffSpecs(from: OpenAPISpec, to: OpenAPISpec): SpecDiff {
+  return {
+    paths: diffRecords(operations(from), operations(to)),
+    schemas: diffRecords(from.components?.schemas || {}, to.components?.schemas || {}),
+  };
+}
+
+export async function saveSpec(version: string, spec: OpenAPISpec): Promise<void> {
+  await useStorage("mosaic").setItem(`${SPECS_PREFIX}:${version}`, spec);
+}
+
+export async function getSpec(version: string): Promise<OpenAPISpec | null> {
+  return await useStorage("mosaic").getItem<OpenAPISpec>(`${SPECS_PREFIX}:${version}`);
+}
+
+export async function getSpecVersions(): Promise<string[]> {
+  const keys = await useStorage("mosaic").getKeys(SPECS_PREFIX);
+  return keys.map((key) => key.slice(SPECS_PREFIX.length + 1));
+}
@@ -0,0 +1,268 @@
+import type { z } from "zod";
+import type { PYPROJECT_TOML_SCHEMA } from "./package";
+
+export interface Manifests {
+  /**
+   * Direct dependencies from `package.json`, including dev dependencies.
+   */
+  npm?: Record<string, string>;
+  /**
+   * Direct dependencies from `Cargo.toml`, including dev dependencies.
+   */
+  cargo?: Record<string, unknown>;
+  /**
+   * Direct dependencies from `pyproject.toml`, including optional and dev dependencies,
+   * keyed by their normalized name.
+   */
+  pypi?: Record<string, string>;
+}
+
+export type Ecosystem = keyof Manifests;
+
+export interface Dependency {
+  name: string;
+  ecosystem: Ecosystem;
+  /**
+   * The version requirement, e.g. `^1.2.0` or `>=0.110`.
+   */
+  version?: string;
+}
+
+export interface ProjectStack {
+  owner: string;
+  repository: string;
+  /**
+   * The name of the project, missing on stacks synced before it was stored.
+   */
+  name?: string;
+  syncedAt: string;
+  stacks: Pick<StackMatcher, "id" | "name">[];
+  dependencies: Dependency[];
+}
+
+const PROJECT_STACKS_PREFIX = "project-stacks";
+
+export interface StackMatcher {
+  id: string;
+  name: string;
+  match: (manifests: Manifests) => boolean;
+}
+
+function npm(...packages: string[]): StackMatcher["match"] {
+  return (manifests) => packages.some((name) => !!manifests.npm && name in manifests.npm);
+}
+
+function cargo(...crates: string[]): StackMatcher["match"] {
+  return (manifests) => crates.some((name) => !!manifests.cargo && name in manifests.cargo);
+}
+
+function pypi(...packages: string[]): StackMatcher["match"] {
+  return (manifests) => packages.some((name) => !!manifests.pypi && name in manifests.pypi);
+}
+
+/**
+ * The stacks that can be detected. To detect a new stack, add a matcher here.
+ * A project can match multiple stacks, e.g. both `Nuxt` and `Vue`.
+ */
+export const STACK_MATCHERS: StackMatcher[] = [
+  { id: "nuxt", name: "Nuxt", match: npm("nuxt", "nuxt3") },
+  { id: "astro", name: "Astro", match: npm("astro") },
+  { id: "next", name: "Next.js", match: npm("next") },
+  { id: "nitro", name: "Nitro", match: npm("nitropack", "nitro") },
+  { id: "hono", name: "Hono", match: npm("hono") },
+  { id: "vue", name: "Vue", match: npm("vue") },
+  { id: "react", name: "React", match: npm("react") },
+  { id: "svelte", name: "Svelte", match: npm("svelte") },
+  { id: "vite", name: "Vite", match: npm("vite") },
+  { id: "vscode", name: "VS Code Extension", match: npm("@types/vscode", "@vscode/vsce") },
+  { id: "unplugin", name: "unplugin", match: npm("unplugin") },
+  { id: "axum", name: "axum", match: cargo("axum") },
+  { id: "actix", name: "Actix Web", match: cargo("actix-web") },
+  { id: "tokio", name: "Tokio", match: cargo("tokio") },
+  { id: "clap", name: "clap", match: cargo("clap") },
+  { id: "wasm", name: "WebAssembly", match: cargo("wasm-bindgen") },
+  { id: "fastapi", name: "FastAPI", match: pypi("fastapi") },
+  { id: "django", name: "Django", match: pypi("django") },
+  { id: "flask", name: "Flask", match: pypi("flask") },
+];
+
+/**
+ * Retrieves the dependency manifests in the root of a repository.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<Manifests>} A promise that resolves to the manifests that exist in the repository.
+ * @throws {GitHubError} If a manifest could not be fetched for any other reason than not existing.
+ */
+export async function getManifests(owner: string, repository: string): Promise<Manifests> {
+  // only a missing manifest is expected, anything else fails the sync so it is retried.
+  const [pkg, cargoManifest, pyproject] = await Promise.all([
+    getPackage(owner, repository).catch((err) => {
+      if (err instanceof GitHubError && err.kind === "not_found") {
+        return undefined;
+      }
+
+      throw err;
+    }),
+    getCargoManifest(owner, repository),
+    getPyprojectManifest(owner, repository),
+  ]);
+
+  return {
+    npm: pkg ? { ...pkg.devDependencies, ...pkg.dependencies } : undefined,
+    cargo: cargoManifest ? { ...cargoManifest["dev-dependencies"], ...cargoManifest.dependencies } : undefined,
+    pypi: pyproject ? getPythonDependencies(pyproject) : undefined,
+  };
+}
+
+/**
+ * Normalizes a python package name, as described in PEP 503.
+ *
+ * @param {string} name - The package name, e.g. `Flask_SQLAlchemy`.
+ * @returns {string} The normalized name, e.g. `flask-sqlalchemy`.
+ */
+export function normalizePythonName(name: string): string {
+  return name.toLowerCase().replace(/[-_.]+/g, "-");
+}
+
+/**
+ * Splits a PEP 508 requirement into the package name and version specifier.
+ *
+ * @param {string} requirement - The requirement, e.g. `fastapi[all]>=0.110; python_version >= "3.9"`.
+ * @returns {[string, string] | undefined} The normalized name and specifier, or `undefined` if the requirement is not valid.
+ */
+function parseRequirement(requirement: string): [string, string] | undefined {
+  const match = requirement.trim().match(/^([A-Z0-9][A-Z0-9._-]*)(?:\[[^\]]*\])?\s*([^;]*)/i);
+  return match ? [normalizePythonName(match[1]), match[2].trim()] : undefined;
+}
+
+function getPythonDependencies(pyproject: z.infer<typeof PYPROJECT_TOML_SCHEMA>): Record<string, string> {
+  const dependencies: Record<string, string> = {};
+
+  const requirements = [
+    ...pyproject.project?.dependencies ?? [],
+    ...Object.values(pyproject.project?.["optional-dependencies"] ?? {}).flat(),
+    // groups can include other groups as `{ include-group = "..." }`, which are not requirements.
+    ...Object.values(pyproject["dependency-groups"] ?? {}).flat().filter((item): item is string => typeof item === "string"),
+  ];
+
+  for (const requirement of requirements) {
+    const parsed = parseRequirement(requirement);
+    if (parsed) {
+      dependencies[parsed[0]] = parsed[1];
+    }
+  }
+
+  const poetry = pyproject.tool?.poetry;
+  for (const group of [poetry?.dependencies, ...Object.values(poetry?.group ?? {}).map((group) => group.dependencies)]) {
+    for (const [name, value] of Object.entries(group ?? {})) {
+      // poetry lists the supported python version as a dependency.
+      if (name === "python") {
+        continue;
+      }
+
+      dependencies[normalizePythonName(name)] = getVersionRequirement(value) ?? "";
+    }
+  }
+
+  return dependencies;
+}
+
+// dependencies are either a version string, or a table with a `version` key.
+function getVersionRequirement(value: unknown): string | undefined {
+  if (typeof value === "string") {
+    return value;
+  }
+
+  if (value && typeof value === "object" && "version" in value && typeof value.version === "string") {
+    return value.version;
+  }
+
+  return undefined;
+}
+
+/**
+ * Flattens the manifests of a project into its direct dependencies.
+ *
+ * @param {Manifests} manifests - The dependency manifests of the project.
+ * @returns {Dependency[]} The dependencies of every ecosystem, sorted by name.
+ */
+export function listDependencies(manifests: Manifests): Dependency[] {
+  const dependencies: Dependency[] = [];
+
+  for (const ecosystem of ["npm", "cargo", "pypi"] as const) {
+    for (const [name, value] of Object.entries(manifests[ecosystem] ?? {})) {
+      dependencies.push({ name, ecosystem, version: getVersionRequirement(value) || undefined });
+    }
+  }
+
+  return dependencies.sort((a, b) => a.name.localeCompare(b.name));
+}
+
+/**
+ * Parses the manifests of a project, and stores its stacks and dependencies.
+ * Projects without a config, or that are ignored, are not synced and their stored stack is removed.
+ * A config that fails to resolve throws, keeping the stored stack until the project is retried.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<ProjectStack | undefined>} A promise that resolves to the stored stack, or `undefined` if the project is not synced.
+ */
+export async function syncProjectStack(owner: string, repository: string): Promise<ProjectStack | undefined> {
+  const storage = useStorage("mosaic");
+  const key = `${PROJECT_STACKS_PREFIX}:${owner}:${repository}`;
+
+  const resolvedMosaicConfig = await resolveMosaicConfig(owner, repository);
+
+  if (resolvedMosaicConfig.type === "error") {
+    throw new Error(resolvedMosaicConfig.message);
+  }
+
+  if (resolvedMosaicConfig.type !== "resolved" || resolvedMosaicConfig.content.project.ignore) {
+    // so projects that became ignored no longer show up as dependents.
+    if (await storage.hasItem(key)) {
+      await storage.removeItem(key);
+    }
+
+    return undefined;
+  }
+
+  const manifests = await getManifests(owner, repository);
+
+  const stack: ProjectStack = {
+    owner,
+    repository,
+    name: resolvedMosaicConfig.content.project.name,
+    syncedAt: new Date().toISOString(),
+    stacks: detectStacks(manifests),
+    dependencies: listDependencies(manifests),
+  };
+
+  await storage.setItem(key, stack);
+
+  return stack;
+}
+
+export async function getProjectStack(owner: string, repository: string): Promise<ProjectStack | null> {
+  return await useStorage("mosaic").getItem<ProjectStack>(`${PROJECT_STACKS_PREFIX}:${owner}:${repository}`);
+}
+
+export function iterateProjectStacks(): AsyncGenerator<ProjectStack> {
+  return iterateStorage<ProjectStack>(PROJECT_STACKS_PREFIX);
+}
+
+/**
+ * Detects the stacks a project is built with.
+ *
+ * @param {Manifests} manifests - The dependency manifests of the project.
+ * @param {StackMatcher[]} [matchers] - The matchers to use.
+ * @returns {Pick<StackMatcher, "id" | "name">[]} The detected stacks.
+ */
+export function detectStacks(
+  manifests: Manifests,
+  matchers: StackMatcher[] = STACK_MATCHERS,
+): Pick<StackMatcher, "id" | "name">[] {
+  return matchers
+    .filter((matcher) => matcher.match(manifests))
+    .map(({ id, name }) => ({ id, name }));
+}
@@ -0,0 +1,106 @@
+export interface StatsSnapshot {
+  /**
+   * The day of the snapshot, as `YYYY-MM-DD`.
+   */
+  date: string;
+  stars: number;
+  forks: number;
+  openIssues: number;
+}
+
+export interface ProjectStats {
+  owner: string;
+  repository: string;
+  snapshots: StatsSnapshot[];
+}
+
+export interface StatsDelta {
+  stars: number;
+  forks: number;
+  openIssues: number;
+}
+
+const PROJECT_STATS_PREFIX = "project-stats";
+
+// a year of daily snapshots is plenty for sparklines, and keeps every entry well below the kv value limit.
+const MAX_SNAPSHOTS = 366;
+
+/**
+ * Records the snapshot of today for a project, replacing an earlier snapshot of the same day.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @param {Omit<StatsSnapshot, "date">} stats - The current stats of the repository.
+ * @returns {Promise<void>} A promise that resolves when the snapshot is stored.
+ */
+export async function recordStatsSnapshot(
+  owner: string,
+  repository: string,
+  stats: Omit<StatsSnapshot, "date">,
+): Promise<void> {
+  const storage = useStorage("mosaic");
+  const key = `${PROJECT_STATS_PREFIX}:${owner}:${repository}`;
+  const date = new Date().toISOString().slice(0, 10);
+
+  const previous = await storage.getItem<ProjectStats>(key);
+  const snapshots = (previous?.snapshots ?? []).filter((snapshot) => snapshot.date !== date);
+
+  snapshots.push({ date, ...stats });
+
+  await storage.setItem(key, {
+    owner,
+    repository,
+    snapshots: snapshots.slice(-MAX_SNAPSHOTS),
+  } satisfies ProjectStats);
+}
+
+export async function getProjectStats(owner: string, repository: string): Promise<ProjectStats | null> {
+  return await useStorage("mosaic").getItem<ProjectStats>(`${PROJECT_STATS_PREFIX}:${owner}:${repository}`);
+}
+
+export function iterateProjectStats(): AsyncGenerator<ProjectStats> {
+  return iterateStorage<ProjectStats>(PROJECT_STATS_PREFIX);
+}
+
+/**
+ * Parses a range like `30d`, `12w` or `6m` into a number of days.
+ *
+ * @param {string} range - The range.
+ * @returns {number | undefined} The number of days, or `undefined` if the range is not valid.
+ */
+export function parseStatsRange(range: string): number | undefined {
+  const match = range.match(/^(\d+)([dwm])$/);
+
+  if (!match) {
+    return undefined;
+  }
+
+  const days = Number(match[1]) * { d: 1, w: 7, m: 30 }[match[2] as "d" | "w" | "m"];
+  return days > 0 && days <= MAX_SNAPSHOTS ? days : undefined;
+}
+
+export function snapshotsInRange(snapshots: StatsSnapshot[], days: number): StatsSnapshot[] {
+  const since = new Date(Date.now() - days * 24 * 60 * 60 * 1000).toISOString().slice(0, 10);
+  return snapshots.filter((snapshot) => snapshot.date >= since);
+}
+
+/**
+ * Computes how much the stats changed between the first and last snapshot.
+ *
+ * @param {StatsSnapshot[]} snapshots - The snapshots, oldest first.
+ * @returns {StatsDelta} The change, zero when there are less than two snapshots.
+ */
+export function computeStatsDelta(snapshots: StatsSnapshot[]): StatsDelta {
+  const first = snapshots[0];
+  const last = snapshots.at(-1);
+
+  if (!first || !last) {
+    return { stars: 0, forks: 0, openIssues: 0 };
+  }
+
+  return {
+    stars: last.stars - first.stars,
+    forks: last.forks - first.forks,
+    openIssues: last.openIssues - first.openIssues,
+  };
+}
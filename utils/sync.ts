@@ -0,0 +1,90 @@
+export interface ProjectSync {
+  owner: string;
+  repository: string;
+  warnings: number;
+  license?: string;
+  fundingLinks: number;
+}
+
+/**
+ * A project shown by mosaic as of the last sync, read by tasks that would otherwise resolve every config again.
+ */
+export interface SyncedProject {
+  owner: string;
+  repository: string;
+  handle: string;
+  syncedAt: string;
+}
+
+const SYNCED_PROJECTS_PREFIX = "synced-projects";
+
+/**
+ * Syncs the stored state of a project that is derived from its config and repository, e.g. config warnings, the handle, the license and funding, so the routes and tasks serving it only read.
+ * Projects without a config are skipped, and so are ignored projects after storing their config warnings.
+ * A config that fails to resolve throws, leaving the stored state untouched until the project is retried.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<ProjectSync | undefined>} A promise that resolves to what was synced, or `undefined` if the project was skipped.
+ */
+export async function syncProject(owner: string, repository: string): Promise<ProjectSync | undefined> {
+  const storage = useStorage("mosaic");
+  const key = `${SYNCED_PROJECTS_PREFIX}:${owner}:${repository}`;
+
+  const resolvedMosaicConfig = await resolveMosaicConfig(owner, repository);
+
+  if (resolvedMosaicConfig.type === "error") {
+    throw new Error(resolvedMosaicConfig.message);
+  }
+
+  if (resolvedMosaicConfig.type !== "resolved") {
+    await storage.removeItem(key);
+    return undefined;
+  }
+
+  await storeConfigWarnings(owner, repository, resolvedMosaicConfig.path, resolvedMosaicConfig.warnings);
+
+  if (resolvedMosaicConfig.content.project.ignore) {
+    await storage.removeItem(key);
+    return undefined;
+  }
+
+  await storage.setItem(key, {
+    owner,
+    repository,
+    handle: resolvedMosaicConfig.content.project.handle || repository,
+    syncedAt: new Date().toISOString(),
+  } satisfies SyncedProject);
+
+  const repositoryNode = await getRepository(owner, repository);
+  const license = await resolveProjectLicense(
+    owner,
+    repository,
+    resolvedMosaicConfig.content.project.license,
+    repositoryNode?.licenseInfo?.spdxId,
+  );
+  const funding = repositoryNode ? await resolveProjectFunding(repositoryNode) : undefined;
+
+  return {
+    owner,
+    repository,
+    warnings: resolvedMosaicConfig.warnings.length,
+    license: license?.spdxId,
+    fundingLinks: funding?.links.length ?? 0,
+  };
+}
+
+/**
+ * Reads the projects stored by the last `projects:sync`.
+ *
+ * @returns {Promise<Map<string, SyncedProject>>} A promise that resolves to the projects keyed by the lowercased `owner/name`.
+ */
+export async function getSyncedProjects(): Promise<Map<string, SyncedProject>> {
+  const projects = new Map<string, SyncedProject>();
+
+  for await (const project of iterateStorage<SyncedProject>(SYNCED_PROJECTS_PREFIX)) {
+    projects.set(`${project.owner}/${project.repository}`.toLowerCase(), project);
+  }
+
+  return projects;
+}
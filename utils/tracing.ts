@@ -0,0 +1,152 @@
+import type { H3Event } from "h3";
+
+export interface Span {
+  traceId: string;
+  spanId: string;
+  parentSpanId?: string;
+  /**
+   * `server` for the span of an incoming request, `client` for outgoing calls made while handling it.
+   */
+  kind: "server" | "client";
+  name: string;
+  startTime: number;
+  endTime?: number;
+  attributes: Record<string, string | number | boolean>;
+  error?: string;
+}
+
+declare module "h3" {
+  interface H3EventContext {
+    requestId?: string;
+    traceId?: string;
+    rootSpan?: Span;
+    spans?: Span[];
+  }
+}
+
+function randomHex(bytes: number): string {
+  return Array.from(crypto.getRandomValues(new Uint8Array(bytes)), (byte) => byte.toString(16).padStart(2, "0")).join("");
+}
+
+export function createSpan(
+  traceId: string,
+  name: string,
+  attributes: Span["attributes"] = {},
+  parentSpanId?: string,
+  kind: Span["kind"] = parentSpanId ? "client" : "server",
+): Span {
+  return {
+    traceId,
+    spanId: randomHex(8),
+    parentSpanId,
+    kind,
+    name,
+    startTime: Date.now(),
+    attributes,
+  };
+}
+
+/**
+ * Resolves the trace context of a request, continuing the trace from a `traceparent` header if present.
+ *
+ * @param {H3Event} event - The incoming request.
+ * @returns {{ traceId: string; parentSpanId?: string }} A 32 character hex trace id, and the span id of the caller if the trace is continued.
+ */
+export function resolveTraceContext(event: H3Event): { traceId: string; parentSpanId?: string } {
+  // https://www.w3.org/TR/trace-context/#traceparent-header
+  const traceparent = getRequestHeader(event, "traceparent");
+  const [, traceId, parentSpanId] = traceparent?.split("-") || [];
+
+  if (traceId && /^[0-9a-f]{32}$/.test(traceId) && traceId !== "0".repeat(32)) {
+    return {
+      traceId,
+      parentSpanId: parentSpanId && /^[0-9a-f]{16}$/.test(parentSpanId) && parentSpanId !== "0".repeat(16) ? parentSpanId : undefined,
+    };
+  }
+
+  return { traceId: randomHex(16) };
+}
+
+/**
+ * Runs `fn` inside of a span that is attached to the current request.
+ * Outside of a request, `fn` is run without tracing.
+ *
+ * @param {string} name - The name of the span, e.g. `github.readme`.
+ * @param {Span["attributes"]} attributes - Attributes describing the span.
+ * @param {() => Promise<T>} fn - The function to trace.
+ * @returns {Promise<T>} The result of `fn`.
+ */
+export async function withSpan<T>(
+  name: string,
+  attributes: Span["attributes"],
+  fn: () => Promise<T>,
+): Promise<T> {
+  let event: H3Event | undefined;
+  try {
+    event = useEvent();
+  } catch {
+    // not inside of a request, e.g. a scheduled task.
+  }
+
+  if (!event?.context.traceId) {
+    return fn();
+  }
+
+  const span = createSpan(event.context.traceId, name, attributes, event.context.rootSpan?.spanId);
+  (event.context.spans ||= []).push(span);
+
+  try {
+    return await fn();
+  } catch (err) {
+    span.error = err instanceof Error ? err.message : String(err);
+    throw err;
+  } finally {
+    span.endTime = Date.now();
+  }
+}
+
+/**
+ * Exports spans to an OTLP/HTTP collector, using the JSON encoding.
+ *
+ * @param {string} endpoint - The base url of the collector, e.g. `http://localhost:4318`.
+ * @param {Span[]} spans - The finished spans.
+ * @returns {Promise<void>}
+ */
+export async function exportSpans(endpoint: string, spans: Span[]): Promise<void> {
+  const toNanos = (ms: number) => `${BigInt(ms) * 1_000_000n}`;
+
+  await fetch(`${endpoint.replace(/\/$/, "")}/v1/traces`, {
+    method: "POST",
+    headers: {
+      "Content-Type": "application/json",
+    },
+    body: JSON.stringify({
+      resourceSpans: [{
+        resource: {
+          attributes: [{ key: "service.name", value: { stringValue: "mosaic" } }],
+        },
+        scopeSpans: [{
+          scope: { name: "mosaic" },
+          spans: spans.map((span) => ({
+            traceId: span.traceId,
+            spanId: span.spanId,
+            parentSpanId: span.parentSpanId,
+            name: span.name,
+            kind: span.kind === "client" ? 3 : 2, // CLIENT : SERVER
+            startTimeUnixNano: toNanos(span.startTime),
+            endTimeUnixNano: toNanos(span.endTime ?? Date.now()),
+            attributes: Object.entries(span.attributes).map(([key, value]) => ({
+              key,
+              value: typeof value === "number"
+                ? { intValue: value }
+                : typeof value === "boolean"
+                  ? { boolValue: value }
+                  : { stringValue: value },
+            })),
+            status: span.error ? { code: 2, message: span.error } : { code: 1 },
+          })),
+        }],
+      }],
+    }),
+  });
+}
@@ -0,0 +1,118 @@
+import { z } from "zod";
+
+const VERCEL_DEPLOYMENTS_SCHEMA = z.object({
+  deployments: z.array(
+    z.object({
+      uid: z.string(),
+      url: z.string().nullish(),
+      state: z.enum(["BUILDING", "ERROR", "INITIALIZING", "QUEUED", "READY", "CANCELED", "DELETED"]).optional(),
+      target: z.string().nullish(),
+      created: z.number(),
+      inspectorUrl: z.string().nullish(),
+    }),
+  ),
+});
+
+export interface VercelDeployment {
+  id: string;
+  url?: string;
+  state?: z.infer<typeof VERCEL_DEPLOYMENTS_SCHEMA>["deployments"][number]["state"];
+  target?: string;
+  createdAt: string;
+  inspectorUrl?: string;
+}
+
+/**
+ * Retrieves the latest deployments of a Vercel project.
+ *
+ * @param {string} project - The name or id of the Vercel project.
+ * @param {object} [options] - Options for filtering the deployments.
+ * @param {string} [options.target] - Only include deployments to this target (e.g. `production`).
+ * @param {number} [options.limit] - The maximum number of deployments to return.
+ * @returns {Promise<VercelDeployment[] | undefined>} A promise that resolves to the deployments sorted from newest to oldest, or `undefined` if they could not be fetched.
+ */
+export async function getVercelDeployments(
+  project: string,
+  options: { target?: string; limit?: number } = {},
+): Promise<VercelDeployment[] | undefined> {
+  const runtimeConfig = useRuntimeConfig();
+
+  if (!project || !runtimeConfig.vercel.token) {
+    return undefined;
+  }
+
+  const url = new URL("https://api.vercel.com/v6/deployments");
+  url.searchParams.set("projectId", project);
+  url.searchParams.set("limit", String(options.limit ?? 10));
+
+  if (options.target) {
+    url.searchParams.set("target", options.target);
+  }
+
+  if (runtimeConfig.vercel.teamId) {
+    url.searchParams.set("teamId", runtimeConfig.vercel.teamId);
+  }
+
+  try {
+    const res = await fetch(url, {
+      headers: {
+        Authorization: `Bearer ${runtimeConfig.vercel.token}`,
+      },
+    });
+
+    if (!res.ok) {
+      console.error(`invalid response from vercel for ${project}`, res.status);
+      return undefined;
+    }
+
+    const { deployments } = await VERCEL_DEPLOYMENTS_SCHEMA.parseAsync(await res.json());
+
+    return deployments.map((deployment) => ({
+      id: deployment.uid,
+      url: deployment.url ? `https://${deployment.url}` : undefined,
+      state: deployment.state,
+      target: deployment.target || undefined,
+      createdAt: new Date(deployment.created).toISOString(),
+      inspectorUrl: deployment.inspectorUrl || undefined,
+    }));
+  } catch (err) {
+    console.error("Error fetching deployments from vercel:", err);
+    return undefined;
+  }
+}
+
+/**
+ * Whether the latest production deployment is missing or failed.
+ *
+ * @param {VercelDeployment[] | undefined} deployments - The deployments of the project, newest first.
+ * @returns {boolean} `true` if the website is likely broken.
+ */
+export function isDeploymentBroken(deployments: VercelDeployment[] | undefined): boolean {
+  const latest = deployments?.[0];
+
+  return !latest || latest.state === "ERROR" || latest.state === "CANCELED" || latest.state === "DELETED";
+}
+
+/**
+ * Resolves the status of the latest production deployment of a Vercel project.
+ *
+ * @param {string} project - The name or id of the Vercel project.
+ * @returns {Promise<{ state?: string; url?: string; createdAt?: string; broken: boolean } | undefined>} A promise that resolves to the deployment status, or `undefined` if vercel is not configured.
+ */
+export async function getWebsiteDeployment(project: string) {
+  const runtimeConfig = useRuntimeConfig();
+
+  if (!runtimeConfig.vercel.token) {
+    return undefined;
+  }
+
+  const deployments = await getVercelDeployments(project, { target: "production", limit: 1 });
+  const latest = deployments?.[0];
+
+  return {
+    state: latest?.state,
+    url: latest?.url,
+    createdAt: latest?.createdAt,
+    broken: isDeploymentBroken(deployments),
+  };
+}
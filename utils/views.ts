@@ -0,0 +1,130 @@
+import type { H3Event } from "h3";
+
+export interface ProjectViews {
+  owner: string;
+  repository: string;
+  /**
+   * The number of views per day, keyed by `YYYY-MM-DD`.
+   */
+  days: Record<string, number>;
+}
+
+const PROJECT_VIEWS_PREFIX = "project-views";
+
+// a little over a year, so "this month last year" can still be compared.
+const MAX_DAYS = 400;
+
+// kv has no atomic increment and allows about one write per second per key,
+// so the views of a day are spread over shards that are summed when read.
+const VIEW_SHARDS = 8;
+
+const BOT_USER_AGENT = /bot|crawl|spider|slurp|preview|headless|lighthouse|curl|wget|python-requests|axios|node-fetch|go-http-client|java\//i;
+
+/**
+ * Whether a view should be ignored, because it comes from a bot or a prefetch instead of a visitor.
+ *
+ * @param {H3Event} event - The request event.
+ * @returns {boolean} `true` if the view should not be counted.
+ */
+export function isBotView(event: H3Event): boolean {
+  const userAgent = getRequestHeader(event, "user-agent");
+
+  if (!userAgent || BOT_USER_AGENT.test(userAgent)) {
+    return true;
+  }
+
+  const purpose = getRequestHeader(event, "sec-purpose") || getRequestHeader(event, "purpose");
+  return !!purpose && /prefetch|prerender/i.test(purpose);
+}
+
+/**
+ * Counts a view of a project in a random shard of the current day. Shards expire after `MAX_DAYS`.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @returns {Promise<void>} A promise that resolves when the view is stored.
+ */
+export async function recordProjectView(owner: string, repository: string): Promise<void> {
+  const storage = useStorage("mosaic");
+  const date = new Date().toISOString().slice(0, 10);
+  const shard = Math.floor(Math.random() * VIEW_SHARDS);
+  const key = `${PROJECT_VIEWS_PREFIX}:${owner}:${repository}:${date}:${shard}`;
+
+  const count = await storage.getItem<number>(key) ?? 0;
+  await storage.setItem(key, count + 1, { ttl: MAX_DAYS * 24 * 60 * 60 });
+}
+
+/**
+ * Sums the shards of every day a project was viewed.
+ *
+ * @param {string} owner - The owner of the repository.
+ * @param {string} repository - The name of the repository.
+ * @param {string} [since] - Only read days from this date on, as `YYYY-MM-DD`.
+ * @returns {Promise<ProjectViews | null>} A promise that resolves to the views per day, or `null` if the project has none.
+ */
+export async function getProjectViews(owner: string, repository: string, since?: string): Promise<ProjectViews | null> {
+  const storage = useStorage("mosaic");
+  const keys = (await storage.getKeys(`${PROJECT_VIEWS_PREFIX}:${owner}:${repository}`))
+    .filter((key) => !since || key.split(":").at(-2)! >= since);
+
+  if (!keys.length) {
+    return null;
+  }
+
+  const days: Record<string, number> = {};
+  const counts = await Promise.all(keys.map((key) => storage.getItem<number>(key)));
+
+  for (const [index, key] of keys.entries()) {
+    const date = key.split(":").at(-2)!;
+    days[date] = (days[date] ?? 0) + (counts[index] ?? 0);
+  }
+
+  return { owner, repository, days };
+}
+
+/**
+ * Whether views can be counted for a project, only projects with a resolved config that are not ignored are counted.
+ * Cached, so counting a view doesn't fetch the config from GitHub every time.
+ */
+export const isViewableProject = defineCachedFunction(async (owner: string, repository: string): Promise<boolean> => {
+  const resolvedMosaicConfig = await resolveMosaicConfig(owner, repository);
+  return resolvedMosaicConfig.type === "resolved" && !resolvedMosaicConfig.content.project.ignore;
+}, {
+  name: "viewable-project",
+  maxAge: 60 * 60, // 1 hour
+  getKey: (owner: string, repository: string) => `${owner}:${repository}`,
+});
+
+/**
+ * The first day `summarizeViews` reads, either the start of the range or the start of the current month.
+ *
+ * @param {number} days - The number of days in the range.
+ * @returns {string} The date, as `YYYY-MM-DD`.
+ */
+export function viewsSince(days: number): string {
+  const since = new Date(Date.now() - days * 24 * 60 * 60 * 1000).toISOString().slice(0, 10);
+  const month = `${new Date().toISOString().slice(0, 7)}-01`;
+
+  return since < month ? since : month;
+}
+
+/**
+ * Sums the views of a project within the last days, and within the current month.
+ *
+ * @param {ProjectViews | null} views - The stored views of the project.
+ * @param {number} days - The number of days to include in `total` and `daily`.
+ * @returns {{ total: number; month: number; daily: { date: string; views: number }[] }} The aggregated views.
+ */
+export function summarizeViews(views: ProjectViews | null, days: number): { total: number; month: number; daily: { date: string; views: number }[] } {
+  const since = new Date(Date.now() - days * 24 * 60 * 60 * 1000).toISOString().slice(0, 10);
+  const month = new Date().toISOString().slice(0, 7);
+
+  const entries = Object.entries(views?.days ?? {}).sort(([a], [b]) => a.localeCompare(b));
+  const daily = entries.filter(([date]) => date >= since).map(([date, count]) => ({ date, views: count }));
+
+  return {
+    total: daily.reduce((total, day) => total + day.views, 0),
+    month: entries.filter(([date]) => date.startsWith(month)).reduce((total, [, count]) => total + count, 0),
+    daily,
+  };
+}
@@ -0,0 +1,9 @@
+import { defineConfig } from "vitest/config";
+
+export default defineConfig({
+  test: {
+    include: ["test/**/*.test.ts"],
+    // nitro auto-imports are stubbed per test with `vi.stubGlobal`.
+    unstubGlobals: true,
+  },
+});